@@ -1,70 +1,243 @@
-use clap::{Parser, Subcommand};
+use clap::error::ErrorKind;
+use clap::{ArgMatches, FromArgMatches, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use advent_of_code::registry;
+use advent_of_code::solver::Part;
 
 #[derive(Parser, Debug)]
-#[command(author, version)]
+#[command(author, version, arg_required_else_help = true)]
 pub struct Args {
+    /// Year of the problems to solve. Defaults to the latest year with a registered solver.
+    #[arg(long, global = true)]
+    pub year: Option<u16>,
+
+    /// Read the puzzle input from this file instead of the day's input in the input store.
+    /// Use `-` to read it from stdin.
+    #[arg(long, global = true, value_name = "PATH")]
+    pub input: Option<PathBuf>,
+
+    /// Solve with this implementation instead of the default one, see `--list-impls`.
+    /// Verifying and benchmarking then only cover this implementation.
+    #[arg(long = "impl", global = true, value_name = "NAME")]
+    pub implementation: Option<String>,
+
+    /// Read the inputs of this user's profile from the input store, instead of the
+    /// one configured by `AOC_USER` or the config file.
+    #[arg(long, global = true, value_name = "NAME")]
+    pub user: Option<String>,
+
+    /// How to print the answers.
+    #[arg(long, global = true, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+
+    /// Also report, for each part solved, the time spent parsing the input, solving
+    /// it and rendering the answer, along with the number of heap allocations.
+    #[arg(long, global = true)]
+    pub profile: bool,
+
+    /// List every registered solver.
+    #[arg(long)]
+    pub list: bool,
+
+    /// List the implementations of every part, starting with the default one.
+    #[arg(long)]
+    pub list_impls: bool,
+
     #[command(subcommand)]
-    pub day: Day,
+    pub command: Option<Command>,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy)]
+pub enum Format {
+    /// The answer alone when solving a single part, or a table of the answers otherwise.
+    Text,
+    /// One JSON object per line and per part solved.
+    Json,
+    /// Tab-separated values, with a header line.
+    Tsv,
 }
 
 #[derive(Subcommand, Debug)]
-pub enum Day {
-    /// Solve day 1's problem.
-    Day1 {
+pub enum Command {
+    /// Solve both parts of every day's problem, and print a table of the results.
+    All,
+    /// Solve both parts of every day's problem with every implementation, and compare
+    /// the answers with the known-good ones from the input store.
+    Verify,
+    /// Benchmark every registered implementation, and print statistics of how long
+    /// they take per iteration.
+    Bench(BenchOptions),
+    /// Generate a random, well-formed puzzle input for a day's problem, and print it.
+    Generate(GenerateOptions),
+    /// Solve parts of a day's problem again every time its input changes, until
+    /// interrupted, and print how the answers and timings changed.
+    Watch(WatchOptions),
+    /// Draw what solving parts of a day's problem computes from its input, e.g. a
+    /// path over a map, for the days which support it.
+    Visualize {
         #[command(subcommand)]
-        part: Part,
+        selection: DaySelection,
     },
-    /// Solve day 2's problem.
-    Day2 {
-        #[command(subcommand)]
-        part: Part,
+    /// Create the module of a new day of the selected year, with a placeholder solver
+    /// and empty inputs, and register its solver.
+    NewDay {
+        /// Day of the problem, between 1 and 25.
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
     },
-    /// Solve day 3's problem.
-    Day3 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 4's problem.
-    Day4 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 5's problem.
-    Day5 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 6's problem.
-    Day6 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 7's problem.
-    Day7 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 8's problem.
-    Day8 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 9's problem.
-    Day9 {
-        #[command(subcommand)]
-        part: Part,
-    },
-    /// Solve day 10's problem.
-    Day10 {
-        #[command(subcommand)]
-        part: Part,
+    /// Print statistics of a private leaderboard from its JSON export: every member's
+    /// stars and local score, completion times, and rankings over time.
+    Leaderboard {
+        /// Path of the JSON export, as downloaded from the leaderboard's "[API]" link.
+        path: PathBuf,
     },
+    #[command(flatten)]
+    Day(DaySelection),
 }
 
-#[derive(Subcommand, Debug)]
-pub enum Part {
+#[derive(clap::Args, Debug)]
+pub struct BenchOptions {
+    /// Only benchmark the implementations of this day's problem.
+    #[arg(long)]
+    pub day: Option<u8>,
+
+    /// Number of samples to take per implementation.
+    #[arg(long, default_value_t = 50)]
+    pub samples: usize,
+
+    /// How long to run each implementation before sampling it, in milliseconds.
+    #[arg(long, value_name = "MS", default_value_t = 300)]
+    pub warm_up: u64,
+
+    /// Benchmark against a random input holding about this many items (e.g. lines),
+    /// instead of the input from the input store.
+    #[arg(long, value_name = "SIZE")]
+    pub generate: Option<usize>,
+
+    /// Seed of the random inputs generated with `--generate`.
+    #[arg(long, default_value_t = 2022, requires = "generate")]
+    pub seed: u64,
+
+    /// Write the results to the `src/year[Y]/day[X]/README.md` of the days with alternative
+    /// implementations.
+    #[arg(long)]
+    pub write_readme: bool,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct GenerateOptions {
+    /// Day of the problem to generate an input for.
+    #[arg(long)]
+    pub day: u8,
+
+    /// Seed of the random generator, to reproduce an input. By default, a random
+    /// seed is picked and printed to stderr.
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Roughly how many items (e.g. lines) the input holds.
+    #[arg(long, default_value_t = 1000)]
+    pub size: usize,
+}
+
+#[derive(clap::Args, Debug)]
+pub struct WatchOptions {
+    /// How often to check the input for changes, in milliseconds.
+    #[arg(long, value_name = "MS", default_value_t = 500)]
+    pub interval: u64,
+
+    #[command(subcommand)]
+    pub selection: DaySelection,
+}
+
+/// Selects parts of a day's problem, via the `day[X]` subcommands. These are
+/// generated from the registry, so that days do not need to be listed by hand.
+/// Every day registered for any year has a subcommand, as the year is only
+/// known once the arguments are parsed.
+#[derive(Debug)]
+pub struct DaySelection {
+    pub day: u8,
+    pub part: PartSelection,
+}
+
+impl DaySelection {
+    fn registered_days() -> Vec<u8> {
+        let mut days: Vec<_> = registry::years().flat_map(registry::days).collect();
+        days.sort_unstable();
+        days.dedup();
+        days
+    }
+
+    fn parse_day(subcommand: &str) -> Option<u8> {
+        let day = subcommand.strip_prefix("day")?.parse().ok()?;
+        Self::registered_days().contains(&day).then_some(day)
+    }
+}
+
+impl FromArgMatches for DaySelection {
+    fn from_arg_matches(matches: &ArgMatches) -> Result<Self, clap::Error> {
+        match matches.subcommand() {
+            Some((subcommand, part_matches)) => match Self::parse_day(subcommand) {
+                Some(day) => Ok(Self {
+                    day,
+                    part: PartSelection::from_arg_matches(part_matches)?,
+                }),
+                None => Err(clap::Error::raw(
+                    ErrorKind::InvalidSubcommand,
+                    format!("no solver is registered for `{subcommand}`\n"),
+                )),
+            },
+            None => Err(clap::Error::raw(
+                ErrorKind::MissingSubcommand,
+                "a day must be selected\n",
+            )),
+        }
+    }
+
+    fn update_from_arg_matches(&mut self, matches: &ArgMatches) -> Result<(), clap::Error> {
+        *self = Self::from_arg_matches(matches)?;
+        Ok(())
+    }
+}
+
+impl Subcommand for DaySelection {
+    fn augment_subcommands(command: clap::Command) -> clap::Command {
+        Self::registered_days()
+            .into_iter()
+            .fold(command, |command, day| {
+                let day_command = clap::Command::new(format!("day{day}"))
+                    .about(format!("Solve day {day}'s problem"))
+                    .subcommand_required(true);
+                command.subcommand(PartSelection::augment_subcommands(day_command))
+            })
+    }
+
+    fn augment_subcommands_for_update(command: clap::Command) -> clap::Command {
+        Self::augment_subcommands(command)
+    }
+
+    fn has_subcommand(name: &str) -> bool {
+        Self::parse_day(name).is_some()
+    }
+}
+
+#[derive(Subcommand, Debug, Clone, Copy)]
+pub enum PartSelection {
     /// Solve the 1st part of the problem.
     Part1,
     /// Solve the 2nd part of the problem.
     Part2,
+    /// Solve both parts of the problem, and print a table of the results.
+    AllParts,
+}
+
+impl PartSelection {
+    pub fn parts(self) -> &'static [Part] {
+        match self {
+            Self::Part1 => &[Part::Part1],
+            Self::Part2 => &[Part::Part2],
+            Self::AllParts => &Part::ALL,
+        }
+    }
 }
@@ -0,0 +1,329 @@
+use serde::Serialize;
+use std::hint::black_box;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::error::Result;
+use crate::input;
+use crate::registry::{self, Entry};
+
+/// How to sample a solver.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    // How long to run the solver before sampling, to warm up caches and
+    // estimate how long one iteration takes.
+    pub warm_up: Duration,
+    pub samples: usize,
+    // Fast solvers are run multiple times per sample, so that each sample
+    // takes about this long, and is not dominated by the timer's resolution.
+    pub sample_time: Duration,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            warm_up: Duration::from_millis(300),
+            samples: 50,
+            sample_time: Duration::from_millis(10),
+        }
+    }
+}
+
+/// Statistics over the time per iteration of every sample.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    // Samples further than 1.5 (mild) or 3 (severe) times the interquartile
+    // range from the 1st or 3rd quartile.
+    pub mild_outliers: usize,
+    pub severe_outliers: usize,
+}
+
+impl Stats {
+    /// Computes the statistics of the given samples, in nanoseconds.
+    pub fn new(mut samples: Vec<f64>) -> Self {
+        assert!(!samples.is_empty(), "at least one sample is needed");
+        samples.sort_by(f64::total_cmp);
+
+        let len = samples.len() as f64;
+        let mean = samples.iter().sum::<f64>() / len;
+        let variance = if samples.len() > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample - mean).powi(2))
+                .sum::<f64>()
+                / (len - 1.)
+        } else {
+            0.
+        };
+
+        let q1 = Self::percentile(&samples, 0.25);
+        let q3 = Self::percentile(&samples, 0.75);
+        let iqr = q3 - q1;
+        let outside = |factor: f64| {
+            samples
+                .iter()
+                .filter(|&&sample| sample < q1 - factor * iqr || sample > q3 + factor * iqr)
+                .count()
+        };
+        let severe_outliers = outside(3.);
+
+        Self {
+            mean: Duration::from_nanos(mean as u64),
+            median: Duration::from_nanos(Self::percentile(&samples, 0.5) as u64),
+            std_dev: Duration::from_nanos(variance.sqrt() as u64),
+            min: Duration::from_nanos(samples[0] as u64),
+            max: Duration::from_nanos(samples[samples.len() - 1] as u64),
+            mild_outliers: outside(1.5) - severe_outliers,
+            severe_outliers,
+        }
+    }
+
+    // Linearly interpolates between the closest ranks of the sorted samples.
+    fn percentile(sorted_samples: &[f64], percentile: f64) -> f64 {
+        let rank = percentile * (sorted_samples.len() - 1) as f64;
+        let lower = sorted_samples[rank.floor() as usize];
+        let upper = sorted_samples[rank.ceil() as usize];
+        lower + (upper - lower) * rank.fract()
+    }
+}
+
+pub struct Benchmark {
+    pub entry: &'static Entry,
+    pub iterations_per_sample: u32,
+    pub stats: Stats,
+}
+
+/// Samples how long the solver takes to solve the given input.
+pub fn bench(entry: &'static Entry, input: &str, config: &Config) -> Result<Benchmark> {
+    // Benchmarking how fast errors are reported is pointless, so bail out early.
+    entry.solve(input)?;
+    let run = || {
+        // The answer is discarded, but the solver must not be optimized away.
+        let _ = black_box(entry.solve(black_box(input)));
+    };
+
+    let warm_up_start = Instant::now();
+    let mut warm_up_iterations = 0u32;
+    loop {
+        run();
+        warm_up_iterations += 1;
+        if warm_up_start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+    let iteration_time = warm_up_start.elapsed() / warm_up_iterations;
+    let iterations_per_sample = (config.sample_time.as_nanos() / iteration_time.as_nanos().max(1))
+        .clamp(1, u32::MAX.into()) as u32;
+
+    let samples = (0..config.samples.max(1))
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations_per_sample {
+                run();
+            }
+            start.elapsed().as_nanos() as f64 / f64::from(iterations_per_sample)
+        })
+        .collect();
+
+    Ok(Benchmark {
+        entry,
+        iterations_per_sample,
+        stats: Stats::new(samples),
+    })
+}
+
+/// A benchmark, as printed by `bench --format json` or `--format tsv`.
+#[derive(Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub implementation: &'static str,
+    pub mean_ns: u128,
+    pub median_ns: u128,
+    pub std_dev_ns: u128,
+    pub min_ns: u128,
+    pub max_ns: u128,
+    pub mild_outliers: usize,
+    pub severe_outliers: usize,
+}
+
+impl From<&Benchmark> for Record {
+    fn from(benchmark: &Benchmark) -> Self {
+        let stats = &benchmark.stats;
+        Self {
+            year: benchmark.entry.year,
+            day: benchmark.entry.day,
+            part: benchmark.entry.part.number(),
+            implementation: benchmark.entry.implementation,
+            mean_ns: stats.mean.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            std_dev_ns: stats.std_dev.as_nanos(),
+            min_ns: stats.min.as_nanos(),
+            max_ns: stats.max.as_nanos(),
+            mild_outliers: stats.mild_outliers,
+            severe_outliers: stats.severe_outliers,
+        }
+    }
+}
+
+/// Renders a markdown table of the benchmarks, one row per implementation.
+pub fn table<'a>(benchmarks: impl IntoIterator<Item = &'a Benchmark>) -> String {
+    let header = [
+        "Day",
+        "Part",
+        "Implementation",
+        "Mean",
+        "Median",
+        "Std. dev.",
+        "Outliers",
+    ]
+    .map(str::to_owned);
+    let rows: Vec<[String; 7]> = benchmarks
+        .into_iter()
+        .map(|benchmark| {
+            let stats = &benchmark.stats;
+            [
+                benchmark.entry.day.to_string(),
+                benchmark.entry.part.to_string(),
+                benchmark.entry.implementation.to_owned(),
+                format!("{:.2?}", stats.mean),
+                format!("{:.2?}", stats.median),
+                format!("{:.2?}", stats.std_dev),
+                format!(
+                    "{} mild, {} severe",
+                    stats.mild_outliers, stats.severe_outliers
+                ),
+            ]
+        })
+        .collect();
+
+    // Durations may be in µs, so widths are counted in chars rather than bytes.
+    let mut widths = header.clone().map(|cell| cell.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render_row = |row: &[String; 7]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            // Only the implementation and the outliers are left-aligned.
+            .map(|(i, (cell, width))| match i {
+                2 | 6 => format!("{cell:width$}"),
+                _ => format!("{cell:>width$}"),
+            })
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+    let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+
+    let mut table = render_row(&header);
+    table.push_str(&format!("|-{}-|\n", separator.join("-|-")));
+    for row in &rows {
+        table.push_str(&render_row(row));
+    }
+    table
+}
+
+/// The README of a day, with the table of its benchmarks. Only days with
+/// alternative implementations of a part have one.
+pub fn readme(year: u16, day: u8, benchmarks: &[Benchmark]) -> Option<String> {
+    let day_benchmarks: Vec<_> = benchmarks
+        .iter()
+        .filter(|benchmark| (benchmark.entry.year, benchmark.entry.day) == (year, day))
+        .collect();
+    let has_alternatives = day_benchmarks.iter().any(|benchmark| {
+        day_benchmarks.iter().any(|other| {
+            other.entry.part == benchmark.entry.part
+                && other.entry.implementation != benchmark.entry.implementation
+        })
+    });
+
+    has_alternatives.then(|| {
+        format!(
+            "# Benchmark Results\n\n\
+             *Note: the smaller the time per iteration, the better. \
+             Regenerate these results with `cargo run --release -- bench --year {year} --day {day} --write-readme`.*\n\n{}",
+            table(day_benchmarks)
+        )
+    })
+}
+
+/// Writes the README of every day of the year which has one, see `readme`.
+/// Returns the READMEs which could not be written, along with why.
+pub fn write_readmes(year: u16, benchmarks: &[Benchmark]) -> Vec<(PathBuf, io::Error)> {
+    registry::days(year)
+        .filter_map(|day| {
+            let readme = readme(year, day, benchmarks)?;
+            let path = input::day_dir(year, day).join("README.md");
+            std::fs::write(&path, readme)
+                .err()
+                .map(|error| (path, error))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_report_outliers() {
+        let mut samples = vec![10., 11., 12., 13., 14., 15., 16., 17.];
+        // Q1 is 12.25 and Q3 is 16.75, so the IQR is 4.5.
+        samples.push(22.);
+        samples.push(40.);
+
+        let stats = Stats::new(samples);
+        assert_eq!(stats.median, Duration::from_nanos(14));
+        assert_eq!(stats.min, Duration::from_nanos(10));
+        assert_eq!(stats.max, Duration::from_nanos(40));
+        assert_eq!((stats.mild_outliers, stats.severe_outliers), (0, 1));
+    }
+
+    // Every implementation of the day, as if each iteration took 1µs.
+    fn benchmarks(year: u16, day: u8) -> Vec<Benchmark> {
+        registry::entries()
+            .iter()
+            .filter(|entry| (entry.year, entry.day) == (year, day))
+            .map(|entry| Benchmark {
+                entry,
+                iterations_per_sample: 1,
+                stats: Stats::new(vec![1000.]),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let benchmarks = benchmarks(2022, 6);
+        let table = table(&benchmarks);
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), benchmarks.len() + 2);
+        assert!(lines[2].contains("| 1.00µs |"));
+        let width = lines[0].chars().count();
+        assert!(lines.iter().all(|line| line.chars().count() == width));
+    }
+
+    #[test]
+    fn readmes_are_only_written_for_alternatives() {
+        let day6_table = table(&benchmarks(2022, 6));
+        let mut year_benchmarks = benchmarks(2022, 1);
+        year_benchmarks.extend(benchmarks(2022, 6));
+
+        assert!(readme(2022, 1, &year_benchmarks).is_none());
+        let readme = readme(2022, 6, &year_benchmarks).unwrap();
+        assert!(readme.starts_with("# Benchmark Results\n"));
+        assert!(readme.ends_with(&day6_table));
+    }
+}
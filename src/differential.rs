@@ -0,0 +1,156 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::error::Error;
+use crate::input;
+use crate::registry;
+use crate::runner;
+use crate::solver::Part;
+
+/// How many random inputs to cross-check implementations against, and how to generate them.
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    pub random_inputs: u64,
+    // The i-th random input is generated from `seed + i`, so that it can be reproduced.
+    pub seed: u64,
+    // Random inputs hold between 1 and `max_size` items, to also cover tiny edge cases.
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            random_inputs: 500,
+            seed: 2022,
+            max_size: 100,
+        }
+    }
+}
+
+/// Implementations of a part that did not all agree on the answer of an input.
+pub struct Disagreement {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // Where the input comes from, e.g. its path or how it was generated.
+    pub source: String,
+    pub input: String,
+    // The answer of every implementation, or why it failed.
+    pub answers: Vec<(&'static str, std::result::Result<Answer, String>)>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} day {}, part {}: implementations disagree on {}",
+            self.year, self.day, self.part, self.source
+        )?;
+        for (implementation, answer) in &self.answers {
+            match answer {
+                Ok(answer) => writeln!(f, "  {implementation}: {answer}")?,
+                Err(error) => writeln!(f, "  {implementation}: FAILED ({error})")?,
+            }
+        }
+        // Real inputs are too long to be worth printing.
+        if self.input.len() <= 1_000 {
+            write!(f, "  input: {:?}", self.input)?;
+        }
+        Ok(())
+    }
+}
+
+/// Solves the input with every implementation of the part, and returns their
+/// answers if they do not all agree. Implementations may fail with different
+/// errors on malformed inputs, but none should ever panic.
+pub fn cross_check(
+    year: u16,
+    day: u8,
+    part: Part,
+    source: &str,
+    input: &str,
+) -> Option<Disagreement> {
+    let runs: Vec<_> = registry::implementations(year, day, part)
+        .map(|entry| runner::run_entry(entry, input))
+        .collect();
+
+    let panicked = runs
+        .iter()
+        .any(|run| matches!(run.answer, Err(Error::Panic(_))));
+    let first_answer = runs.first()?.answer.as_ref().ok();
+    let agree = runs
+        .iter()
+        .all(|run| run.answer.as_ref().ok() == first_answer);
+    if agree && !panicked {
+        return None;
+    }
+
+    Some(Disagreement {
+        year,
+        day,
+        part,
+        source: source.to_owned(),
+        input: input.to_owned(),
+        answers: runs
+            .into_iter()
+            .map(|run| {
+                let answer = run.answer.map_err(|error| error.to_string());
+                (run.implementation.unwrap(), answer)
+            })
+            .collect(),
+    })
+}
+
+/// Cross-checks the implementations of every part of the day that has alternative
+/// ones, against the day's checked-in input and against random inputs, if the day
+/// can generate them.
+pub fn check_day(year: u16, day: u8, config: &Config) -> Vec<Disagreement> {
+    let parts: Vec<_> = Part::ALL
+        .into_iter()
+        .filter(|&part| registry::implementations(year, day, part).count() > 1)
+        .collect();
+    if parts.is_empty() {
+        return Vec::new();
+    }
+
+    let mut inputs = Vec::new();
+    if let Ok(input) = input::read(year, day, None) {
+        let path = input::default_path(year, day);
+        inputs.push((path.display().to_string(), input));
+    }
+    for i in 0..config.random_inputs {
+        let seed = config.seed.wrapping_add(i);
+        let mut rng = StdRng::seed_from_u64(seed);
+        let size = rng.gen_range(1..=config.max_size.max(1));
+        match registry::generate_input(year, day, &mut rng, size) {
+            Some(input) => inputs.push((format!("a random input (seed {seed})"), input)),
+            None => break,
+        }
+    }
+
+    inputs
+        .iter()
+        .flat_map(|(source, input)| {
+            parts
+                .iter()
+                .filter_map(|&part| cross_check(year, day, part, source, input))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alternative_implementations_agree() {
+        let disagreements: Vec<_> = registry::years()
+            .flat_map(|year| registry::days(year).map(move |day| (year, day)))
+            .flat_map(|(year, day)| check_day(year, day, &Config::default()))
+            .map(|disagreement| disagreement.to_string())
+            .collect();
+        assert!(disagreements.is_empty(), "{}", disagreements.join("\n"));
+    }
+}
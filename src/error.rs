@@ -0,0 +1,155 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub type Result<T> = std::result::Result<T, Error>;
+pub type ParseResult<T> = std::result::Result<T, ParseError>;
+
+#[derive(Debug)]
+pub enum Error {
+    /// The puzzle input could not be read.
+    Io { path: PathBuf, source: io::Error },
+    /// The puzzle input is malformed.
+    Parse(ParseError),
+    /// The puzzle input is well-formed, but has no answer.
+    NoSolution(String),
+    /// The solver panicked.
+    Panic(String),
+    /// No solver is registered for the requested part.
+    NotRegistered,
+    /// The requested part has solvers, but none with the requested name.
+    UnknownImplementation {
+        name: String,
+        available: Vec<&'static str>,
+    },
+}
+
+impl Error {
+    /// Attaches the path of the input being solved to parse errors, and to
+    /// errors reading the input while solving it.
+    pub fn in_file(self, path: &Path) -> Self {
+        match self {
+            Self::Parse(error) => Self::Parse(ParseError {
+                path: Some(path.to_path_buf()),
+                ..error
+            }),
+            Self::Io {
+                path: unknown_path,
+                source,
+            } if unknown_path.as_os_str().is_empty() => Self::Io {
+                path: path.to_path_buf(),
+                source,
+            },
+            error => error,
+        }
+    }
+
+    /// Renders the error like a compiler would, pointing at the offending token
+    /// of the input if there is one.
+    pub fn diagnostic(&self) -> String {
+        match self {
+            Self::Parse(error) => error.diagnostic(),
+            error => format!("error: {error}"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, source } => write!(f, "could not read {}: {source}", path.display()),
+            Self::Parse(error) => write!(f, "{error}"),
+            Self::NoSolution(reason) => write!(f, "no solution: {reason}"),
+            Self::Panic(reason) => write!(f, "panicked: {reason}"),
+            Self::NotRegistered => write!(f, "no solver is registered"),
+            Self::UnknownImplementation { name, available } => write!(
+                f,
+                "no implementation named `{name}` is registered, expected one of `{}`",
+                available.join("`, `")
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// Solvers reading their input incrementally do not know its path, see `Error::in_file`.
+impl From<io::Error> for Error {
+    fn from(source: io::Error) -> Self {
+        Self::Io {
+            path: PathBuf::new(),
+            source,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+/// Describes what was expected at a given position of the puzzle input, and what was found instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    // Only known once the solver returns, see `Error::in_file`.
+    pub path: Option<PathBuf>,
+    // Both the line and the column are 1-based.
+    pub line: usize,
+    pub column: usize,
+    // The whole offending line, to point at the offending token. Unknown for
+    // lines too long to be kept in memory, e.g. when reading a huge datastream.
+    pub line_text: Option<String>,
+    // Length in characters of the offending token.
+    pub length: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    fn location(&self) -> String {
+        let path = self
+            .path
+            .as_deref()
+            .map_or_else(|| "<input>".into(), Path::to_string_lossy);
+        format!("{path}:{}:{}", self.line, self.column)
+    }
+
+    pub fn diagnostic(&self) -> String {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let header = format!(
+            "error: expected {}, found {}\n{gutter}--> {}",
+            self.expected,
+            self.found,
+            self.location()
+        );
+        match &self.line_text {
+            Some(line_text) => format!(
+                "{header}\n{gutter} |\n{} | {line_text}\n{gutter} | {}{}",
+                self.line,
+                " ".repeat(self.column - 1),
+                "^".repeat(self.length.max(1)),
+            ),
+            None => header,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, found {}",
+            self.location(),
+            self.expected,
+            self.found
+        )
+    }
+}
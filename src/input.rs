@@ -0,0 +1,56 @@
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+use crate::store;
+
+/// Directory of the given day's solver, along with its checked-in input.
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    PathBuf::from(format!("src/year{year}/day{day}"))
+}
+
+/// Path of the day's input in the installed input store, which defaults to the
+/// input checked in alongside the day's solver, see `store::current`.
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    store::current().input_path(year, day)
+}
+
+/// Path of the input to show in diagnostics, where stdin is shown as `<stdin>`.
+pub fn display_path(year: u16, day: u8, path: Option<&Path>) -> PathBuf {
+    match path {
+        Some(path) if path == Path::new("-") => PathBuf::from("<stdin>"),
+        Some(path) => path.to_path_buf(),
+        None => default_path(year, day),
+    }
+}
+
+/// Whether the input is read from stdin, i.e. its path is `-`.
+pub fn is_stdin(path: Option<&Path>) -> bool {
+    path == Some(Path::new("-"))
+}
+
+/// Opens the puzzle input for `day`, to read it incrementally. See `read` for
+/// how `path` is interpreted.
+pub fn open(year: u16, day: u8, path: Option<&Path>) -> io::Result<Box<dyn BufRead>> {
+    match path {
+        Some(path) if is_stdin(Some(path)) => Ok(Box::new(io::stdin().lock())),
+        Some(path) => Ok(Box::new(BufReader::new(File::open(path)?))),
+        None => Ok(Box::new(BufReader::new(File::open(default_path(
+            year, day,
+        ))?))),
+    }
+}
+
+/// Reads the puzzle input for `day`. If `path` is `-`, the input is read from stdin.
+/// If no `path` is given, we fall back to the day's input in the input store.
+pub fn read(year: u16, day: u8, path: Option<&Path>) -> io::Result<String> {
+    match path {
+        Some(path) if path == Path::new("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => std::fs::read_to_string(path),
+        None => std::fs::read_to_string(default_path(year, day)),
+    }
+}
@@ -0,0 +1,460 @@
+//! Statistics of a private leaderboard, from its JSON export.
+
+use serde::de::{self, Unexpected};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+/// A private leaderboard, as exported by the Advent Of Code website.
+#[derive(Debug, Deserialize)]
+pub struct Leaderboard {
+    // The year of the event, exported as a string.
+    #[serde(deserialize_with = "deserialize_year")]
+    pub event: u16,
+    pub members: HashMap<String, Member>,
+}
+
+fn deserialize_year<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u16, D::Error> {
+    let event = String::deserialize(deserializer)?;
+    event
+        .parse()
+        .map_err(|_| de::Error::invalid_value(Unexpected::Str(&event), &"a year like \"2022\""))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Member {
+    pub id: u64,
+    // Members may stay anonymous.
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u32,
+    // The stars of every day, by part.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u8, BTreeMap<u8, Star>>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Star {
+    // Unix timestamp of when the star was earned.
+    pub get_star_ts: i64,
+}
+
+impl Member {
+    /// Name of the member, as shown on the website.
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn star_ts(&self, day: u8, part: u8) -> Option<i64> {
+        Some(self.completion_day_level.get(&day)?.get(&part)?.get_star_ts)
+    }
+}
+
+/// Statistics of every member of a leaderboard.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub event: String,
+    // Ordered by rank.
+    pub standings: Vec<Standing>,
+    // Only days with at least one star.
+    pub days: Vec<DayReport>,
+    // The ranking at the end of every day with at least one star.
+    pub rankings: Vec<DayRanking>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Standing {
+    // Members with the same score share their rank.
+    pub rank: usize,
+    pub id: u64,
+    pub member: String,
+    pub stars: u32,
+    pub local_score: u32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    // Only members with at least one star, ordered by when they earned the 1st one.
+    pub completions: Vec<Completion>,
+}
+
+/// How long a member took to earn each star of a day, since the day's puzzle unlocked.
+#[derive(Debug, Serialize)]
+pub struct Completion {
+    pub id: u64,
+    pub member: String,
+    pub part1_seconds: Option<i64>,
+    pub part2_seconds: Option<i64>,
+    // Time between the 1st and 2nd star.
+    pub delta_seconds: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayRanking {
+    pub day: u8,
+    // Ordered by rank.
+    pub members: Vec<RankedMember>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RankedMember {
+    pub id: u64,
+    pub member: String,
+    pub rank: usize,
+    // Local score earned with the stars of this day and the ones before it.
+    pub local_score: u32,
+}
+
+impl Leaderboard {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Members ordered by id, so that reports do not depend on the export's order.
+    fn members(&self) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.values().collect();
+        members.sort_by_key(|member| member.id);
+        members
+    }
+
+    pub fn report(&self) -> Report {
+        let members = self.members();
+
+        let mut standings: Vec<_> = members
+            .iter()
+            .map(|member| Standing {
+                rank: 0,
+                id: member.id,
+                member: member.display_name(),
+                stars: member.stars,
+                local_score: member.local_score,
+            })
+            .collect();
+        standings.sort_by_key(|standing| (Reverse(standing.local_score), Reverse(standing.stars)));
+        let scores: Vec<_> = standings
+            .iter()
+            .map(|standing| standing.local_score)
+            .collect();
+        for (standing, rank) in standings.iter_mut().zip(ranks(&scores)) {
+            standing.rank = rank;
+        }
+
+        let solved_days: Vec<u8> = (1..=25)
+            .filter(|&day| {
+                members
+                    .iter()
+                    .any(|member| member.completion_day_level.contains_key(&day))
+            })
+            .collect();
+
+        let days = solved_days
+            .iter()
+            .map(|&day| {
+                let unlock = unlock_timestamp(self.event.into(), day);
+                let mut completions: Vec<_> = members
+                    .iter()
+                    .filter_map(|member| {
+                        let part1 = member.star_ts(day, 1)?;
+                        let part2 = member.star_ts(day, 2);
+                        Some((
+                            part1,
+                            Completion {
+                                id: member.id,
+                                member: member.display_name(),
+                                part1_seconds: Some(part1 - unlock),
+                                part2_seconds: part2.map(|part2| part2 - unlock),
+                                delta_seconds: part2.map(|part2| part2 - part1),
+                            },
+                        ))
+                    })
+                    .collect();
+                completions.sort_by_key(|(part1, _)| *part1);
+                DayReport {
+                    day,
+                    completions: completions
+                        .into_iter()
+                        .map(|(_, completion)| completion)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        // Every star earns as many points as there are members who did not earn it before.
+        let mut scores = vec![0; members.len()];
+        let rankings = solved_days
+            .iter()
+            .map(|&day| {
+                for part in [1, 2] {
+                    let mut solvers: Vec<_> = (0..members.len())
+                        .filter_map(|i| Some((members[i].star_ts(day, part)?, i)))
+                        .collect();
+                    solvers.sort();
+                    for (position, (_, i)) in solvers.into_iter().enumerate() {
+                        scores[i] += (members.len() - position) as u32;
+                    }
+                }
+
+                let mut order: Vec<_> = (0..members.len()).collect();
+                order.sort_by_key(|&i| Reverse(scores[i]));
+                let sorted_scores: Vec<_> = order.iter().map(|&i| scores[i]).collect();
+                DayRanking {
+                    day,
+                    members: order
+                        .into_iter()
+                        .zip(ranks(&sorted_scores))
+                        .map(|(i, rank)| RankedMember {
+                            id: members[i].id,
+                            member: members[i].display_name(),
+                            rank,
+                            local_score: scores[i],
+                        })
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Report {
+            event: self.event.to_string(),
+            standings,
+            days,
+            rankings,
+        }
+    }
+}
+
+/// Ranks of scores sorted in decreasing order, where equal scores share their rank.
+fn ranks(sorted_scores: &[u32]) -> Vec<usize> {
+    let mut ranks = Vec::with_capacity(sorted_scores.len());
+    for (i, score) in sorted_scores.iter().enumerate() {
+        let rank = match i {
+            0 => 1,
+            _ if sorted_scores[i - 1] == *score => ranks[i - 1],
+            _ => i + 1,
+        };
+        ranks.push(rank);
+    }
+    ranks
+}
+
+/// Puzzles unlock at midnight EST (UTC-5), i.e. at 5am UTC, in December.
+pub fn unlock_timestamp(year: i64, day: u8) -> i64 {
+    // Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let (month, day) = (12, day as i64);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month - 3) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146_097 + day_of_era - 719_468;
+    days * 86_400 + 5 * 3_600
+}
+
+/// Formats a number of seconds as `HH:MM:SS`, where hours may exceed a day.
+fn format_seconds(seconds: Option<i64>) -> String {
+    match seconds {
+        Some(seconds) => format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3_600,
+            seconds % 3_600 / 60,
+            seconds % 60
+        ),
+        None => "-".to_owned(),
+    }
+}
+
+/// Renders a markdown table, where only the given columns are left-aligned.
+fn table(header: &[&str], rows: &[Vec<String>], left_aligned: &[usize]) -> String {
+    let mut widths: Vec<_> = header.iter().map(|cell| cell.chars().count()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let render_row = |row: Vec<&str>| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &width))| match left_aligned.contains(&i) {
+                true => format!("{cell:width$}"),
+                false => format!("{cell:>width$}"),
+            })
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+    let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+
+    let mut table = render_row(header.to_vec());
+    table.push_str(&format!("|-{}-|\n", separator.join("-|-")));
+    for row in rows {
+        table.push_str(&render_row(row.iter().map(String::as_str).collect()));
+    }
+    table
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "# Leaderboard {}\n", self.event)?;
+        let rows: Vec<_> = self
+            .standings
+            .iter()
+            .map(|standing| {
+                vec![
+                    standing.rank.to_string(),
+                    standing.member.clone(),
+                    standing.stars.to_string(),
+                    standing.local_score.to_string(),
+                ]
+            })
+            .collect();
+        write!(
+            f,
+            "{}",
+            table(&["Rank", "Member", "Stars", "Local score"], &rows, &[1])
+        )?;
+
+        for day in &self.days {
+            writeln!(f, "\n## Day {}\n", day.day)?;
+            let rows: Vec<_> = day
+                .completions
+                .iter()
+                .map(|completion| {
+                    vec![
+                        completion.member.clone(),
+                        format_seconds(completion.part1_seconds),
+                        format_seconds(completion.part2_seconds),
+                        format_seconds(completion.delta_seconds),
+                    ]
+                })
+                .collect();
+            write!(
+                f,
+                "{}",
+                table(&["Member", "Part 1", "Part 2", "Delta"], &rows, &[0])
+            )?;
+        }
+
+        if let Some(last_ranking) = self.rankings.last() {
+            writeln!(f, "\n## Rankings Over Time\n")?;
+            // One row per member, in the order of the final ranking, and one column per day.
+            let mut header = vec!["Member".to_owned()];
+            header.extend(
+                self.rankings
+                    .iter()
+                    .map(|ranking| format!("Day {}", ranking.day)),
+            );
+            let rows: Vec<_> = last_ranking
+                .members
+                .iter()
+                .map(|ranked| {
+                    let mut row = vec![ranked.member.clone()];
+                    row.extend(self.rankings.iter().map(|ranking| {
+                        let rank = ranking
+                            .members
+                            .iter()
+                            .find(|other| other.id == ranked.id)
+                            .map_or(0, |other| other.rank);
+                        format!("#{rank}")
+                    }));
+                    row
+                })
+                .collect();
+            let header: Vec<_> = header.iter().map(String::as_str).collect();
+            write!(f, "{}", table(&header, &rows, &[0]))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Day 1 of 2022 unlocked at 1669870800.
+    const EXPORT: &str = r#"{
+        "event": "2022",
+        "owner_id": 1,
+        "members": {
+            "1": {"id": 1, "name": "Alice", "stars": 3, "local_score": 7, "global_score": 0, "last_star_ts": 0,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1669870900, "star_index": 0}, "2": {"get_star_ts": 1669871000, "star_index": 1}},
+                      "2": {"1": {"get_star_ts": 1669960000, "star_index": 4}}}},
+            "2": {"id": 2, "name": null, "stars": 3, "local_score": 8, "global_score": 0, "last_star_ts": 0,
+                  "completion_day_level": {
+                      "1": {"1": {"get_star_ts": 1669870860, "star_index": 2}, "2": {"get_star_ts": 1669874400, "star_index": 3}},
+                      "2": {"1": {"get_star_ts": 1669957500, "star_index": 5}}}},
+            "3": {"id": 3, "name": "Carol", "stars": 0, "local_score": 0, "global_score": 0, "last_star_ts": 0,
+                  "completion_day_level": {}}
+        }
+    }"#;
+
+    #[test]
+    fn unlock_timestamps() {
+        assert_eq!(unlock_timestamp(2022, 1), 1_669_870_800);
+        assert_eq!(unlock_timestamp(2022, 25), 1_671_944_400);
+    }
+
+    #[test]
+    fn rejects_events_which_are_not_years() {
+        let error = Leaderboard::from_json(&EXPORT.replace(r#""2022""#, r#""AoC""#)).unwrap_err();
+        assert!(error.to_string().contains("a year"));
+    }
+
+    #[test]
+    fn reports_statistics_of_every_member() {
+        let report = Leaderboard::from_json(EXPORT).unwrap().report();
+
+        let standings: Vec<_> = report
+            .standings
+            .iter()
+            .map(|standing| (standing.rank, standing.member.as_str()))
+            .collect();
+        assert_eq!(
+            standings,
+            [(1, "(anonymous user #2)"), (2, "Alice"), (3, "Carol")]
+        );
+
+        let day1 = &report.days[0].completions;
+        assert_eq!(day1[0].member, "(anonymous user #2)");
+        assert_eq!(
+            (
+                day1[0].part1_seconds,
+                day1[0].part2_seconds,
+                day1[0].delta_seconds
+            ),
+            (Some(60), Some(3_600), Some(3_540))
+        );
+        assert_eq!(day1[1].delta_seconds, Some(100));
+        assert_eq!(report.days[1].completions[1].part2_seconds, None);
+
+        // Both share the lead after day 1, until the anonymous user solves day 2 first.
+        let ranking = |day: usize| -> Vec<_> {
+            report.rankings[day]
+                .members
+                .iter()
+                .map(|ranked| (ranked.member.as_str(), ranked.rank, ranked.local_score))
+                .collect()
+        };
+        assert_eq!(
+            ranking(0),
+            [
+                ("Alice", 1, 5),
+                ("(anonymous user #2)", 1, 5),
+                ("Carol", 3, 0)
+            ]
+        );
+        assert_eq!(
+            ranking(1),
+            [
+                ("(anonymous user #2)", 1, 8),
+                ("Alice", 2, 7),
+                ("Carol", 3, 0)
+            ]
+        );
+    }
+}
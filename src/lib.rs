@@ -0,0 +1,33 @@
+//! Solvers of the [Advent Of Code](https://adventofcode.com) problems, of every year.
+//!
+//! Every year's module holds a module per day, which exposes its parsers, domain
+//! types and solver. The solvers can be looked up by year, day and part via the
+//! [`registry`].
+
+#[macro_use]
+extern crate lazy_static;
+
+#[macro_use]
+pub mod registry;
+
+pub mod answer;
+pub mod bench;
+pub mod differential;
+pub mod error;
+pub mod input;
+pub mod leaderboard;
+pub mod memory;
+pub mod parse;
+pub mod profile;
+pub mod runner;
+pub mod scaffold;
+pub mod solver;
+pub mod store;
+pub mod verify;
+pub mod watch;
+
+pub mod year2022;
+
+register_years! {
+    year2022,
+}
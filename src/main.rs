@@ -1,49 +1,747 @@
-#![feature(test)]
-#[macro_use]
-extern crate lazy_static;
-extern crate test;
-
 use clap::Parser;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::hint;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use advent_of_code::answer::Answer;
+use advent_of_code::bench;
+use advent_of_code::input;
+use advent_of_code::leaderboard::Leaderboard;
+use advent_of_code::memory::CountingAllocator;
+use advent_of_code::registry;
+use advent_of_code::runner::{self, Run};
+use advent_of_code::scaffold;
+use advent_of_code::solver::Part;
+use advent_of_code::store::{self, Store};
+use advent_of_code::verify;
+use advent_of_code::watch;
 
 mod args;
-mod day1;
-mod day10;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod solver;
-
-use args::{Args, Day};
-use day1::Day1Solver;
-use day10::Day10Solver;
-use day2::Day2Solver;
-use day3::Day3Solver;
-use day4::Day4Solver;
-use day5::Day5Solver;
-use day6::Day6Solver;
-use day7::Day7Solver;
-use day8::Day8Solver;
-use day9::Day9Solver;
-use solver::Solver;
+
+use args::{Args, BenchOptions, Command, DaySelection, Format, GenerateOptions, PartSelection};
+
+// Counts heap allocations, to report the peak memory usage of every run.
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// Like `print!` and `println!`, but exit quietly once stdout is closed, e.g. when
+// piped into `head`, rather than panicking.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        write_stdout(format_args!($($arg)*))
+    };
+}
+
+macro_rules! outln {
+    () => {
+        write_stdout(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        write_stdout(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+fn write_stdout(args: fmt::Arguments) {
+    if let Err(error) = io::stdout().lock().write_fmt(args) {
+        exit_on_write_error(error);
+    }
+}
+
+/// Exits successfully if stdout was closed by the reader, as there is no one left
+/// to print to, or with an error otherwise.
+fn exit_on_write_error(error: io::Error) -> ! {
+    if error.kind() == io::ErrorKind::BrokenPipe {
+        process::exit(0);
+    }
+    eprintln!("Cannot write to stdout: {error}");
+    process::exit(1);
+}
 
 fn main() {
     let cli = Args::parse();
+    let input = cli.input.as_deref();
+    let implementation = cli.implementation.as_deref();
+
+    let store = match Store::from_env() {
+        Ok(store) => store,
+        Err(error) => {
+            eprintln!("Cannot configure the input store: {error}");
+            process::exit(2);
+        }
+    };
+    let store = match &cli.user {
+        Some(user) => store.with_user(user).unwrap_or_else(|| {
+            eprintln!(
+                "`--user` requires an input store, see `{}` or `{}`.",
+                store::ROOT_VAR,
+                store::DEFAULT_CONFIG_PATH
+            );
+            process::exit(2);
+        }),
+        None => store,
+    };
+    store::install(store).unwrap();
+
+    if cli.list {
+        print_list();
+    }
+    if cli.list_impls {
+        print_implementations();
+    }
+    if let Some(name) = implementation {
+        if registry::entries()
+            .iter()
+            .all(|entry| entry.implementation != name)
+        {
+            eprintln!("No implementation named `{name}` is registered, see `--list-impls`.");
+            process::exit(2);
+        }
+    }
+
+    if cli.profile && !matches!(cli.command, None | Some(Command::All | Command::Day(_))) {
+        eprintln!("`--profile` can only be used when solving problems.");
+        process::exit(2);
+    }
+
+    let year = match cli.year {
+        Some(year) => year,
+        None => registry::years()
+            .last()
+            .expect("at least one year is registered"),
+    };
+    // Only new days can be of a year without registered solvers.
+    if !matches!(
+        cli.command,
+        None | Some(Command::NewDay { .. } | Command::Leaderboard { .. })
+    ) && registry::years().all(|registered_year| registered_year != year)
+    {
+        eprintln!("No solver is registered for {year}.");
+        process::exit(2);
+    }
+
+    let runs: Vec<_> = match cli.command {
+        None => return,
+        Some(Command::All) => {
+            if input.is_some() {
+                eprintln!("`--input` cannot be used when solving every day's problem.");
+                process::exit(2);
+            }
+            if implementation.is_some() {
+                eprintln!("`--impl` cannot be used when solving every day's problem.");
+                process::exit(2);
+            }
+
+            registry::days(year)
+                .flat_map(|day| runner::run(year, day, PartSelection::AllParts.parts(), None, None))
+                .collect()
+        }
+        Some(Command::Verify) => {
+            if input.is_some() {
+                eprintln!("`--input` cannot be used when verifying answers.");
+                process::exit(2);
+            }
+
+            match verify::verify_all(year, implementation, &mut io::stdout().lock()) {
+                Ok(true) => {}
+                Ok(false) => process::exit(1),
+                Err(error) => exit_on_write_error(error),
+            }
+            return;
+        }
+        Some(Command::Bench(options)) => {
+            if let Some(day) = options.day {
+                check_registered(year, day);
+            } else if input.is_some() {
+                eprintln!(
+                    "`--input` can only be used when benchmarking a single day, with `--day`."
+                );
+                process::exit(2);
+            }
+            if input.is_some() && options.generate.is_some() {
+                eprintln!("`--input` cannot be used when benchmarking against a random input.");
+                process::exit(2);
+            }
+
+            if !bench_all(year, &options, implementation, input, cli.format) {
+                process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Generate(options)) => {
+            if input.is_some() {
+                eprintln!("`--input` cannot be used when generating an input.");
+                process::exit(2);
+            }
+
+            check_registered(year, options.day);
+            generate(year, &options);
+            return;
+        }
+        Some(Command::NewDay { day }) => {
+            if input.is_some() {
+                eprintln!("`--input` cannot be used when creating a new day.");
+                process::exit(2);
+            }
+
+            match scaffold::new_day(Path::new(""), year, day) {
+                Ok(written) => {
+                    for path in written {
+                        outln!("Wrote {}", path.display());
+                    }
+                    outln!(
+                        "Paste the puzzle's input in {} and its example in {}, then run \
+                         `cargo run -- --year {year} day{day} part1`.",
+                        input::default_path(year, day).display(),
+                        input::day_dir(year, day).join("small.txt").display()
+                    );
+                }
+                Err(error) => {
+                    eprintln!("Could not create day {day} of {year}: {error}.");
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::Leaderboard { path }) => {
+            if input.is_some() {
+                eprintln!("`--input` cannot be used with a leaderboard, pass its path instead.");
+                process::exit(2);
+            }
+            if matches!(cli.format, Format::Tsv) {
+                eprintln!("`--format tsv` cannot be used with a leaderboard.");
+                process::exit(2);
+            }
+
+            print_leaderboard(&path, cli.format);
+            return;
+        }
+        Some(Command::Watch(options)) => {
+            if input::is_stdin(input) {
+                eprintln!("`--input -` cannot be watched, as stdin can only be read once.");
+                process::exit(2);
+            }
+            if !matches!(cli.format, Format::Text) {
+                eprintln!("`--format` cannot be used when watching an input.");
+                process::exit(2);
+            }
+
+            check_registered(year, options.selection.day);
+            let DaySelection { day, part } = options.selection;
+            let interval = Duration::from_millis(options.interval);
+            let error = watch::watch(
+                year,
+                day,
+                part.parts(),
+                implementation,
+                input,
+                interval,
+                &mut io::stdout(),
+            );
+            exit_on_write_error(error);
+        }
+        Some(Command::Visualize { selection }) => {
+            if implementation.is_some() {
+                eprintln!("`--impl` cannot be used when visualizing, as drawings are per day.");
+                process::exit(2);
+            }
+            if !matches!(cli.format, Format::Text) {
+                eprintln!("`--format` cannot be used when visualizing.");
+                process::exit(2);
+            }
+
+            let DaySelection { day, part } = selection;
+            check_registered(year, day);
+            if !visualize(year, day, part.parts(), input) {
+                process::exit(1);
+            }
+            return;
+        }
+        Some(Command::Day(DaySelection { day, part })) => {
+            check_registered(year, day);
+            runner::run(year, day, part.parts(), implementation, input)
+        }
+    };
+
+    let profiles: Option<Vec<_>> = cli
+        .profile
+        .then(|| runs.iter().map(Profile::from).collect());
+    let profiles = profiles.as_deref();
+    match (cli.format, runs.as_slice()) {
+        (Format::Text, [run]) if profiles.is_none() => match &run.answer {
+            Ok(answer) => outln!("{answer}"),
+            Err(error) => eprintln!("{}", error.diagnostic()),
+        },
+        (Format::Text, runs) => print_table(runs, profiles),
+        (Format::Json, runs) => print_json(runs, profiles),
+        (Format::Tsv, runs) => print_tsv(runs, profiles),
+    }
+
+    if runs.iter().any(|run| run.answer.is_err()) {
+        process::exit(1);
+    }
+}
+
+/// Prints the statistics of the leaderboard exported at `path`, as markdown or
+/// as a single JSON object.
+fn print_leaderboard(path: &Path, format: Format) {
+    let leaderboard = fs::read_to_string(path)
+        .map_err(|error| error.to_string())
+        .and_then(|json| Leaderboard::from_json(&json).map_err(|error| error.to_string()));
+    let report = match leaderboard {
+        Ok(leaderboard) => leaderboard.report(),
+        Err(error) => {
+            eprintln!("Cannot read the leaderboard {}: {error}", path.display());
+            process::exit(1);
+        }
+    };
+
+    match format {
+        Format::Json => outln!("{}", serde_json::to_string(&report).unwrap()),
+        _ => out!("{report}"),
+    }
+}
+
+/// Exits if no solver is registered for the day of the year.
+fn check_registered(year: u16, day: u8) {
+    if registry::days(year).all(|registered_day| registered_day != day) {
+        eprintln!("No solver is registered for day {day} of {year}.");
+        process::exit(2);
+    }
+}
+
+fn generate(year: u16, options: &GenerateOptions) {
+    let seed = options.seed.unwrap_or_else(rand::random);
+    let mut rng = StdRng::seed_from_u64(seed);
+    match registry::generate_input(year, options.day, &mut rng, options.size) {
+        Some(input) => {
+            if options.seed.is_none() {
+                eprintln!("Generated with seed {seed}.");
+            }
+            let mut stdout = io::stdout().lock();
+            if let Err(error) = stdout
+                .write_all(input.as_bytes())
+                .and_then(|()| stdout.write_all(b"\n"))
+            {
+                exit_on_write_error(error);
+            }
+        }
+        None => {
+            eprintln!("Day {} cannot generate random inputs.", options.day);
+            process::exit(2);
+        }
+    }
+}
+
+/// Prints what solving the parts computes from the input, and returns whether
+/// they were all drawn.
+fn visualize(year: u16, day: u8, parts: &[Part], input_path: Option<&Path>) -> bool {
+    let path = input::display_path(year, day, input_path);
+    let input = match input::read(year, day, input_path) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Cannot read {}: {error}", path.display());
+            return false;
+        }
+    };
+
+    let mut all_drawn = true;
+    for &part in parts {
+        if parts.len() > 1 {
+            outln!("Day {day}, part {part}:");
+        }
+        match registry::visualize(year, day, part, &input) {
+            Some(Ok(drawing)) => outln!("{drawing}"),
+            Some(Err(error)) => {
+                eprintln!("{}", error.in_file(&path).diagnostic());
+                all_drawn = false;
+            }
+            None => {
+                eprintln!("Day {day} of {year} cannot be visualized.");
+                process::exit(2);
+            }
+        }
+    }
+    all_drawn
+}
 
-    match cli.day {
-        Day::Day1 { part } => Day1Solver::solve(part),
-        Day::Day2 { part } => Day2Solver::solve(part),
-        Day::Day3 { part } => Day3Solver::solve(part),
-        Day::Day4 { part } => Day4Solver::solve(part),
-        Day::Day5 { part } => Day5Solver::solve(part),
-        Day::Day6 { part } => Day6Solver::solve(part),
-        Day::Day7 { part } => Day7Solver::solve(part),
-        Day::Day8 { part } => Day8Solver::solve(part),
-        Day::Day9 { part } => Day9Solver::solve(part),
-        Day::Day10 { part } => Day10Solver::solve(part),
+/// Where the time went when solving a part, see `--profile`.
+struct Profile {
+    // Unknown for solvers parsing their input as they solve it, which is then
+    // part of the time spent solving.
+    parse: Option<Duration>,
+    solve: Duration,
+    // Time spent formatting the answer, or the error, to be printed.
+    render: Duration,
+}
+
+impl From<&Run> for Profile {
+    fn from(run: &Run) -> Self {
+        let start = Instant::now();
+        let rendered = match &run.answer {
+            Ok(answer) => answer.to_string(),
+            Err(error) => error.diagnostic(),
+        };
+        let render = start.elapsed();
+        hint::black_box(rendered);
+
+        Self {
+            parse: run.parse_elapsed,
+            solve: run.elapsed - run.parse_elapsed.unwrap_or_default(),
+            render,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    implementation: Option<&'a str>,
+    answer: Option<&'a Answer>,
+    error: Option<String>,
+    elapsed_ns: u128,
+    peak_memory_bytes: Option<usize>,
+    #[serde(flatten)]
+    profile: Option<ProfileRecord>,
+}
+
+/// Only reported with `--profile`.
+#[derive(Serialize)]
+struct ProfileRecord {
+    parse_ns: Option<u128>,
+    solve_ns: u128,
+    render_ns: u128,
+    allocations: Option<usize>,
+}
+
+impl<'a> Record<'a> {
+    fn new(run: &'a Run, profile: Option<&Profile>) -> Self {
+        Self {
+            year: run.year,
+            day: run.day,
+            part: run.part.number(),
+            implementation: run.implementation,
+            answer: run.answer.as_ref().ok(),
+            error: run.answer.as_ref().err().map(ToString::to_string),
+            elapsed_ns: run.elapsed.as_nanos(),
+            peak_memory_bytes: run.memory.map(|usage| usage.peak),
+            profile: profile.map(|profile| ProfileRecord {
+                parse_ns: profile.parse.map(|parse| parse.as_nanos()),
+                solve_ns: profile.solve.as_nanos(),
+                render_ns: profile.render.as_nanos(),
+                allocations: run.memory.map(|usage| usage.allocations),
+            }),
+        }
+    }
+}
+
+fn records<'a>(runs: &'a [Run], profiles: Option<&[Profile]>) -> Vec<Record<'a>> {
+    runs.iter()
+        .enumerate()
+        .map(|(i, run)| Record::new(run, profiles.map(|profiles| &profiles[i])))
+        .collect()
+}
+
+/// Prints one JSON object per line.
+fn print_json(runs: &[Run], profiles: Option<&[Profile]>) {
+    for record in records(runs, profiles) {
+        outln!("{}", serde_json::to_string(&record).unwrap());
+    }
+}
+
+fn print_tsv(runs: &[Run], profiles: Option<&[Profile]>) {
+    // Tabs and newlines would break the format, so they are escaped.
+    let escape = |value: &str| value.replace('\t', "\\t").replace('\n', "\\n");
+    fn optional(value: Option<impl ToString>) -> String {
+        value.map(|value| value.to_string()).unwrap_or_default()
+    }
+
+    out!("year\tday\tpart\timplementation\tanswer\terror\telapsed_ns\tpeak_memory_bytes");
+    if profiles.is_some() {
+        out!("\tparse_ns\tsolve_ns\trender_ns\tallocations");
+    }
+    outln!();
+    for record in records(runs, profiles) {
+        out!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            record.year,
+            record.day,
+            record.part,
+            record.implementation.unwrap_or_default(),
+            escape(&record.answer.map(ToString::to_string).unwrap_or_default()),
+            escape(&record.error.unwrap_or_default()),
+            record.elapsed_ns,
+            optional(record.peak_memory_bytes),
+        );
+        if let Some(profile) = record.profile {
+            out!(
+                "\t{}\t{}\t{}\t{}",
+                optional(profile.parse_ns),
+                profile.solve_ns,
+                profile.render_ns,
+                optional(profile.allocations),
+            );
+        }
+        outln!();
+    }
+}
+
+fn print_list() {
+    for entry in registry::entries() {
+        outln!(
+            "{} day{:<3} part{} {}",
+            entry.year,
+            entry.day,
+            entry.part,
+            entry.implementation
+        );
+    }
+}
+
+fn print_implementations() {
+    for year in registry::years() {
+        for day in registry::days(year) {
+            for part in Part::ALL {
+                let implementations: Vec<_> = registry::implementations(year, day, part)
+                    .map(|entry| entry.implementation)
+                    .collect();
+                if let Some((default, alternatives)) = implementations.split_first() {
+                    let alternatives = alternatives
+                        .iter()
+                        .map(|alternative| format!(", {alternative}"))
+                        .collect::<String>();
+                    outln!("{year} day{day:<3} part{part} {default} (default){alternatives}");
+                }
+            }
+        }
+    }
+}
+
+/// Benchmarks every implementation of the selected days, and returns whether
+/// they all succeeded.
+fn bench_all(
+    year: u16,
+    options: &BenchOptions,
+    implementation: Option<&str>,
+    input_path: Option<&Path>,
+    format: Format,
+) -> bool {
+    let config = bench::Config {
+        warm_up: Duration::from_millis(options.warm_up),
+        samples: options.samples,
+        ..Default::default()
+    };
+
+    let mut all_succeeded = true;
+    let mut benchmarks = Vec::new();
+    for day in
+        registry::days(year).filter(|&day| options.day.is_none_or(|selected| selected == day))
+    {
+        let (path, input) = match options.generate {
+            Some(size) => {
+                let mut rng = StdRng::seed_from_u64(options.seed);
+                match registry::generate_input(year, day, &mut rng, size) {
+                    Some(input) => (PathBuf::from("<random input>"), input),
+                    None => {
+                        eprintln!("Day {day} cannot generate random inputs, skipping it.");
+                        continue;
+                    }
+                }
+            }
+            None => {
+                let path = input::display_path(year, day, input_path);
+                match input::read(year, day, input_path) {
+                    Ok(input) => (path, input),
+                    Err(error) => {
+                        eprintln!("Day {day}: cannot read {}: {error}", path.display());
+                        all_succeeded = false;
+                        continue;
+                    }
+                }
+            }
+        };
+
+        let entries = registry::entries().iter().filter(|entry| {
+            (entry.year, entry.day) == (year, day)
+                && implementation.is_none_or(|name| entry.implementation == name)
+        });
+        for entry in entries {
+            match bench::bench(entry, &input, &config) {
+                Ok(benchmark) => benchmarks.push(benchmark),
+                Err(error) => {
+                    eprintln!("{}", error.in_file(&path).diagnostic());
+                    all_succeeded = false;
+                }
+            }
+        }
+    }
+
+    match format {
+        Format::Text => out!("{}", bench::table(&benchmarks)),
+        Format::Json => {
+            for benchmark in &benchmarks {
+                let record = bench::Record::from(benchmark);
+                outln!("{}", serde_json::to_string(&record).unwrap());
+            }
+        }
+        Format::Tsv => {
+            outln!("year\tday\tpart\timplementation\tmean_ns\tmedian_ns\tstd_dev_ns\tmin_ns\tmax_ns\tmild_outliers\tsevere_outliers");
+            for benchmark in &benchmarks {
+                let record = bench::Record::from(benchmark);
+                outln!(
+                    "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}",
+                    record.year,
+                    record.day,
+                    record.part,
+                    record.implementation,
+                    record.mean_ns,
+                    record.median_ns,
+                    record.std_dev_ns,
+                    record.min_ns,
+                    record.max_ns,
+                    record.mild_outliers,
+                    record.severe_outliers
+                );
+            }
+        }
+    }
+
+    if options.write_readme {
+        for (path, error) in bench::write_readmes(year, &benchmarks) {
+            eprintln!("Cannot write {}: {error}", path.display());
+            all_succeeded = false;
+        }
+    }
+    all_succeeded
+}
+
+fn print_table(runs: &[Run], profiles: Option<&[Profile]>) {
+    // Multi-line answers span multiple rows of the table.
+    let answers: Vec<Vec<String>> = runs
+        .iter()
+        .map(|run| match &run.answer {
+            Ok(Answer::Grid(rows)) => rows.clone(),
+            Ok(answer) => vec![answer.to_string()],
+            Err(error) => vec![format!("FAILED ({error})")],
+        })
+        .collect();
+
+    // Every column after the answer is right-aligned.
+    let mut columns: Vec<(&str, Vec<String>)> = Vec::new();
+    match profiles {
+        Some(profiles) => {
+            let duration = |duration: Duration| format!("{duration:.2?}");
+            columns.push((
+                "Parse",
+                profiles
+                    .iter()
+                    .map(|profile| profile.parse.map_or_else(|| "-".to_owned(), duration))
+                    .collect(),
+            ));
+            columns.push((
+                "Solve",
+                profiles
+                    .iter()
+                    .map(|profile| duration(profile.solve))
+                    .collect(),
+            ));
+            columns.push((
+                "Render",
+                profiles
+                    .iter()
+                    .map(|profile| duration(profile.render))
+                    .collect(),
+            ));
+        }
+        None => columns.push((
+            "Elapsed",
+            runs.iter()
+                .map(|run| format!("{:.2?}", run.elapsed))
+                .collect(),
+        )),
+    }
+    columns.push((
+        "Peak memory",
+        runs.iter()
+            .map(|run| {
+                run.memory
+                    .map_or_else(|| "-".to_owned(), |usage| format_bytes(usage.peak))
+            })
+            .collect(),
+    ));
+    if profiles.is_some() {
+        columns.push((
+            "Allocations",
+            runs.iter()
+                .map(|run| {
+                    run.memory
+                        .map_or_else(|| "-".to_owned(), |usage| usage.allocations.to_string())
+                })
+                .collect(),
+        ));
+    }
+
+    // Durations may hold a `µ`, so widths count characters rather than bytes.
+    let width = |header: &str, cells: &[String]| {
+        cells
+            .iter()
+            .map(|cell| cell.chars().count())
+            .chain([header.len()])
+            .max()
+            .unwrap()
+    };
+    let answer_width = width("Answer", &answers.concat());
+    let widths: Vec<_> = columns
+        .iter()
+        .map(|(header, cells)| width(header, cells))
+        .collect();
+
+    let mut header = format!("| Day | Part | {:answer_width$} |", "Answer");
+    let mut separator = format!("|-----|------|-{}-|", "-".repeat(answer_width));
+    for ((name, _), width) in columns.iter().zip(&widths) {
+        header += &format!(" {name:>width$} |");
+        separator += &format!("-{}-|", "-".repeat(*width));
+    }
+    outln!("{header}");
+    outln!("{separator}");
+
+    for (i, (run, answer)) in runs.iter().zip(answers).enumerate() {
+        for (j, row) in answer.iter().enumerate() {
+            let mut line = if j == 0 {
+                format!("| {:>3} | {:>4} | {row:answer_width$} |", run.day, run.part)
+            } else {
+                format!("| {:3} | {:4} | {row:answer_width$} |", "", "")
+            };
+            for ((_, cells), width) in columns.iter().zip(&widths) {
+                let cell = if j == 0 { cells[i].as_str() } else { "" };
+                line += &format!(" {cell:>width$} |");
+            }
+            outln!("{line}");
+        }
+    }
+}
+
+/// Formats a number of bytes with binary units, e.g. `1.50 KiB`.
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{bytes} B")
+    } else {
+        format!("{value:.2} {}", UNITS[unit])
     }
 }
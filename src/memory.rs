@@ -0,0 +1,76 @@
+//! Tracks the heap usage and allocations of the process, once `CountingAllocator` is installed
+//! as the global allocator, as the CLI does.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocates through the system allocator, while counting the allocations and the bytes allocated.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Both blocks may coexist while the old one is copied.
+            record_allocation(new_size);
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+        }
+        new_ptr
+    }
+}
+
+fn record_allocation(size: usize) {
+    INSTALLED.store(true, Ordering::Relaxed);
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+/// Heap usage of a measured closure, see `measure`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    // In bytes, above the heap usage when the closure was called.
+    pub peak: usize,
+    // Number of allocations and reallocations.
+    pub allocations: usize,
+}
+
+/// Runs `f`, and measures its heap usage. The usage is unknown if
+/// `CountingAllocator` is not the global allocator.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    let baseline = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(baseline, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let output = f();
+    let usage = Usage {
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(baseline),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+    };
+    (output, INSTALLED.load(Ordering::Relaxed).then_some(usage))
+}
@@ -0,0 +1,242 @@
+use std::io::BufRead;
+use std::iter::Enumerate;
+use std::ops::Range;
+use std::str::FromStr;
+
+use crate::error::{ParseError, ParseResult, Result};
+
+/// Iterates over the lines of a puzzle input, keeping track of their position
+/// so that errors can point at the offending token.
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines {
+        input,
+        lines: input.lines().enumerate(),
+    }
+}
+
+/// Error for an input that ended before `expected` could be found.
+pub fn end_of_input(input: &str, expected: impl Into<String>) -> ParseError {
+    let (line_index, line) = input.lines().enumerate().last().unwrap_or((0, ""));
+    Token {
+        text: "",
+        line,
+        line_number: line_index + 1,
+        offset: line.len(),
+    }
+    .error_found(expected, "end of input")
+}
+
+/// Reads the lines of a puzzle input one at a time, so that only the current
+/// line is held in memory.
+pub fn read_lines<R: BufRead>(reader: R) -> impl Iterator<Item = Result<Line>> {
+    reader.lines().enumerate().map(|(line_index, text)| {
+        Ok(Line {
+            text: text?,
+            number: line_index + 1,
+        })
+    })
+}
+
+/// Error for a datastream that ended before `expected` could be found, when
+/// its lines were too long to be kept. Both the line and column are 1-based.
+pub fn end_of_stream(line: usize, column: usize, expected: impl Into<String>) -> ParseError {
+    ParseError {
+        path: None,
+        line,
+        column,
+        line_text: None,
+        length: 0,
+        expected: expected.into(),
+        found: "end of input".to_owned(),
+    }
+}
+
+/// A line read from a puzzle input, see `read_lines`.
+pub struct Line {
+    pub text: String,
+    // 1-based.
+    pub number: usize,
+}
+
+impl Line {
+    pub fn token(&self) -> Token<'_> {
+        Token {
+            text: &self.text,
+            line: &self.text,
+            line_number: self.number,
+            offset: 0,
+        }
+    }
+
+    /// Error for an input that ended after this line, before `expected` could be found.
+    pub fn end_of_input(&self, expected: impl Into<String>) -> ParseError {
+        let token = self.token();
+        token
+            .slice(token.text.len()..token.text.len())
+            .error_found(expected, "end of input")
+    }
+}
+
+#[derive(Clone)]
+pub struct Lines<'a> {
+    input: &'a str,
+    lines: Enumerate<std::str::Lines<'a>>,
+}
+
+impl<'a> Lines<'a> {
+    pub fn end_of_input(&self, expected: impl Into<String>) -> ParseError {
+        end_of_input(self.input, expected)
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (line_index, line) = self.lines.next()?;
+        Some(Token {
+            text: line,
+            line,
+            line_number: line_index + 1,
+            offset: 0,
+        })
+    }
+}
+
+/// A piece of a line of the puzzle input.
+#[derive(Debug, Clone, Copy)]
+pub struct Token<'a> {
+    pub text: &'a str,
+    line: &'a str,
+    // 1-based.
+    line_number: usize,
+    // Byte offset of the token within its line.
+    offset: usize,
+}
+
+impl<'a> Token<'a> {
+    /// Error for a token that is not what was `expected`.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let found = if self.text.is_empty() {
+            "nothing".to_owned()
+        } else {
+            format!("`{}`", self.text)
+        };
+        self.error_found(expected, found)
+    }
+
+    fn error_found(&self, expected: impl Into<String>, found: impl Into<String>) -> ParseError {
+        ParseError {
+            path: None,
+            line: self.line_number,
+            column: self.line[..self.offset].chars().count() + 1,
+            line_text: Some(self.line.to_owned()),
+            length: self.text.chars().count(),
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    pub fn parse<T: FromStr>(&self, expected: &str) -> ParseResult<T> {
+        self.text.parse().map_err(|_| self.error(expected))
+    }
+
+    /// Splits the token around the first occurrence of `delimiter`.
+    pub fn split_once(
+        &self,
+        delimiter: char,
+        expected: &str,
+    ) -> ParseResult<(Token<'a>, Token<'a>)> {
+        let index = self
+            .text
+            .find(delimiter)
+            .ok_or_else(|| self.error(expected))?;
+        Ok((
+            self.slice(0..index),
+            self.slice(index + delimiter.len_utf8()..self.text.len()),
+        ))
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Token<'a>> {
+        self.text
+            .starts_with(prefix)
+            .then(|| self.slice(prefix.len()..self.text.len()))
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Token<'a>> {
+        self.text
+            .ends_with(suffix)
+            .then(|| self.slice(0..self.text.len() - suffix.len()))
+    }
+
+    /// The token without its leading whitespace, e.g. the indentation of a line.
+    pub fn trim_start(&self) -> Token<'a> {
+        let trimmed = self.text.trim_start();
+        self.slice(self.text.len() - trimmed.len()..self.text.len())
+    }
+
+    /// Splits the token around every occurrence of `delimiter`.
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Token<'a>> + 'a {
+        let token = *self;
+        let mut start = 0;
+        self.text.split(delimiter).map(move |piece| {
+            let piece = token.slice(start..start + piece.len());
+            start += piece.text.len() + delimiter.len();
+            piece
+        })
+    }
+
+    /// Sub-token spanning the given byte range of this token.
+    pub fn slice(&self, range: Range<usize>) -> Token<'a> {
+        Token {
+            text: &self.text[range.clone()],
+            offset: self.offset + range.start,
+            ..*self
+        }
+    }
+
+    /// Iterates over the characters of the token, each along with its own sub-token.
+    pub fn chars(&self) -> impl Iterator<Item = (char, Token<'a>)> {
+        let token = *self;
+        self.text
+            .char_indices()
+            .map(move |(i, character)| (character, token.slice(i..i + character.len_utf8())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        let mut lines = lines("A Y\nB Q\n");
+        lines.next();
+        let (_, symbol) = lines.next().unwrap().split_once(' ', "a space").unwrap();
+
+        let error = symbol.error("one of `X`, `Y` or `Z`");
+        assert_eq!((error.line, error.column, error.length), (2, 3, 1));
+        assert_eq!(error.found, "`Q`");
+        assert_eq!(
+            error.diagnostic(),
+            "error: expected one of `X`, `Y` or `Z`, found `Q`\n --> <input>:2:3\n  |\n2 | B Q\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn end_of_input_points_after_the_last_line() {
+        let error = end_of_input("abc\ndefg", "a marker");
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.found, "end of input");
+    }
+
+    #[test]
+    fn read_lines_like_whole_inputs() {
+        let input = "A Y\r\nB X\n\nC Z\n";
+        let read: Vec<_> = read_lines(input.as_bytes())
+            .map(|line| line.unwrap().text)
+            .collect();
+        let split: Vec<_> = lines(input).map(|token| token.text).collect();
+        assert_eq!(read, split);
+    }
+}
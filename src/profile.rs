@@ -0,0 +1,54 @@
+//! Splits the time spent solving a part between parsing its input and solving
+//! it, for solvers that parse their whole input before solving it.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static PARSED: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Marks the end of parsing the input. Solvers parsing their input as they
+/// solve it, e.g. one line at a time, do not call it.
+pub fn mark_parsed() {
+    PARSED.with(|parsed| parsed.set(Some(Instant::now())));
+}
+
+/// Time spent in a timed closure, see `time`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub elapsed: Duration,
+    // Part of `elapsed` spent reading and parsing the input, if marked by the solver.
+    pub parse: Option<Duration>,
+}
+
+/// Runs `f`, and measures how long it took, and how long it took to parse its
+/// input if `mark_parsed` was called meanwhile.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Timing) {
+    PARSED.with(|parsed| parsed.set(None));
+    let start = Instant::now();
+    let output = f();
+    let elapsed = start.elapsed();
+    let parse = PARSED
+        .with(Cell::take)
+        .map(|parsed| parsed.duration_since(start));
+    (output, Timing { elapsed, parse })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_time_is_part_of_elapsed_time() {
+        let ((), timing) = time(|| {
+            std::thread::sleep(Duration::from_millis(5));
+            mark_parsed();
+        });
+        let parse = timing.parse.unwrap();
+        assert!(parse >= Duration::from_millis(5) && parse <= timing.elapsed);
+
+        let ((), timing) = time(|| {});
+        assert_eq!(timing.parse, None);
+    }
+}
@@ -0,0 +1,226 @@
+use rand::rngs::StdRng;
+use std::io::{self, BufRead};
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::solver::Part;
+
+/// A registered solver for one part of a day's problem.
+#[derive(Clone, Copy)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // Name of the implementation, to tell apart alternative solvers of the same part.
+    pub implementation: &'static str,
+    pub solver: Solve,
+}
+
+/// How a solver reads its input.
+#[derive(Clone, Copy)]
+pub enum Solve {
+    /// Reads the whole input at once.
+    Whole(fn(&str) -> Result<Answer>),
+    /// Reads the input incrementally, so that huge inputs never have to fit in memory.
+    Streaming(fn(&mut dyn BufRead) -> Result<Answer>),
+}
+
+impl Entry {
+    pub fn solve(&self, input: &str) -> Result<Answer> {
+        match self.solver {
+            Solve::Whole(solve) => solve(input),
+            Solve::Streaming(solve) => solve(&mut input.as_bytes()),
+        }
+    }
+
+    /// Solves the input read from `reader`, which is only read whole if the
+    /// solver does not stream its input.
+    pub fn solve_reader(&self, reader: &mut dyn BufRead) -> Result<Answer> {
+        match self.solver {
+            Solve::Whole(solve) => solve(&io::read_to_string(reader)?),
+            Solve::Streaming(solve) => solve(reader),
+        }
+    }
+}
+
+/// Generates a random puzzle input of a given size, see `Solver::generate_input`.
+pub type Generator = fn(&mut StdRng, usize) -> Option<String>;
+
+/// Draws what solving a part computes from the input, see `Solver::visualize`.
+pub type Visualizer = fn(&str, Part) -> Option<Result<Answer>>;
+
+/// Registers the solvers of every day of a year. Each day's module is declared
+/// with a plain `pub mod`, next to the invocation of this macro in the year's
+/// module, so that tools like rustfmt can find it.
+macro_rules! register_days {
+    ($($day:ident::$solver:ident),* $(,)?) => {
+        pub(crate) fn registered_entries() -> Vec<$crate::registry::Entry> {
+            [$(<$day::$solver as $crate::solver::Solver>::entries()),*]
+                .into_iter()
+                .flatten()
+                .collect()
+        }
+
+        pub(crate) fn registered_generators() -> Vec<(u16, u8, $crate::registry::Generator)> {
+            vec![$((
+                <$day::$solver as $crate::solver::Solver>::YEAR,
+                <$day::$solver as $crate::solver::Solver>::DAY,
+                <$day::$solver as $crate::solver::Solver>::generate_input
+                    as $crate::registry::Generator,
+            )),*]
+        }
+
+        pub(crate) fn registered_visualizers() -> Vec<(u16, u8, $crate::registry::Visualizer)> {
+            vec![$((
+                <$day::$solver as $crate::solver::Solver>::YEAR,
+                <$day::$solver as $crate::solver::Solver>::DAY,
+                <$day::$solver as $crate::solver::Solver>::visualize
+                    as $crate::registry::Visualizer,
+            )),*]
+        }
+    };
+}
+
+/// Gathers the solvers of every year, each registering its days with `register_days!`.
+/// Like days, every year's module is declared with a plain `pub mod`.
+macro_rules! register_years {
+    ($($year:ident),* $(,)?) => {
+        fn registered_entries() -> Vec<registry::Entry> {
+            [$($year::registered_entries()),*].concat()
+        }
+
+        fn registered_generators() -> Vec<(u16, u8, registry::Generator)> {
+            [$($year::registered_generators()),*].concat()
+        }
+
+        fn registered_visualizers() -> Vec<(u16, u8, registry::Visualizer)> {
+            [$($year::registered_visualizers()),*].concat()
+        }
+    };
+}
+
+lazy_static! {
+    static ref ENTRIES: Vec<Entry> = {
+        let mut entries = crate::registered_entries();
+        // The sort is stable, so alternative implementations keep their registration order.
+        entries.sort_by_key(|entry| (entry.year, entry.day, entry.part));
+        entries
+    };
+    static ref GENERATORS: Vec<(u16, u8, Generator)> = crate::registered_generators();
+    static ref VISUALIZERS: Vec<(u16, u8, Visualizer)> = crate::registered_visualizers();
+}
+
+/// Every registered solver, ordered by year, day then part.
+pub fn entries() -> &'static [Entry] {
+    &ENTRIES
+}
+
+/// Every year with at least one registered solver, in increasing order.
+pub fn years() -> impl Iterator<Item = u16> {
+    let mut years: Vec<_> = entries().iter().map(|entry| entry.year).collect();
+    years.dedup();
+    years.into_iter()
+}
+
+/// Every day of the year with at least one registered solver, in increasing order.
+pub fn days(year: u16) -> impl Iterator<Item = u8> {
+    let mut days: Vec<_> = entries()
+        .iter()
+        .filter(|entry| entry.year == year)
+        .map(|entry| entry.day)
+        .collect();
+    days.dedup();
+    days.into_iter()
+}
+
+/// Generates a random puzzle input for the given day, if it supports it.
+pub fn generate_input(year: u16, day: u8, rng: &mut StdRng, size: usize) -> Option<String> {
+    let (_, _, generate) = GENERATORS
+        .iter()
+        .find(|(generator_year, generator_day, _)| {
+            (*generator_year, *generator_day) == (year, day)
+        })?;
+    generate(rng, size)
+}
+
+/// Draws what solving the given part of a day's problem computes from the
+/// input, if the day supports it.
+pub fn visualize(year: u16, day: u8, part: Part, input: &str) -> Option<Result<Answer>> {
+    let (_, _, visualize) = VISUALIZERS
+        .iter()
+        .find(|(visualizer_year, visualizer_day, _)| {
+            (*visualizer_year, *visualizer_day) == (year, day)
+        })?;
+    visualize(input, part)
+}
+
+/// Every implementation of the given part of a day's problem, in registration
+/// order, starting with the default one.
+pub fn implementations(year: u16, day: u8, part: Part) -> impl Iterator<Item = &'static Entry> {
+    entries()
+        .iter()
+        .filter(move |entry| entry.year == year && entry.day == day && entry.part == part)
+}
+
+/// Finds the named implementation of the given part of a day's problem. If no
+/// implementation is named, the default one is returned, i.e. the first registered.
+pub fn find(
+    year: u16,
+    day: u8,
+    part: Part,
+    implementation: Option<&str>,
+) -> Option<&'static Entry> {
+    implementations(year, day, part)
+        .find(|entry| implementation.is_none_or(|name| entry.implementation == name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_named_implementations() {
+        let default = find(2022, 6, Part::Part2, None).unwrap();
+        assert_eq!(default.implementation, "even-smarter-loop");
+        let hashset = find(2022, 6, Part::Part2, Some("hashset")).unwrap();
+        assert_eq!(hashset.implementation, "hashset");
+        assert!(find(2022, 6, Part::Part1, Some("hashset")).is_none());
+        assert!(find(2021, 6, Part::Part2, None).is_none());
+    }
+
+    #[test]
+    fn only_some_days_visualize() {
+        let example = "SbcdefghijklmnopqrstuvwxyE";
+        let drawing = visualize(2022, 12, Part::Part1, example).unwrap().unwrap();
+        assert_eq!(drawing, Answer::Grid(vec![format!("{}E", ">".repeat(25))]));
+        assert!(visualize(2022, 11, Part::Part1, example).is_none());
+    }
+
+    #[test]
+    fn every_generated_input_is_solved() {
+        use rand::SeedableRng;
+
+        for year in years() {
+            for day in days(year) {
+                for seed in 0..50 {
+                    let mut rng = StdRng::seed_from_u64(seed);
+                    let size = [1, 10, 100, 1_000][seed as usize % 4];
+                    let Some(input) = generate_input(year, day, &mut rng, size) else {
+                        continue;
+                    };
+                    for entry in entries()
+                        .iter()
+                        .filter(|entry| (entry.year, entry.day) == (year, day))
+                    {
+                        if let Err(error) = entry.solve(&input) {
+                            panic!(
+                                "{year} day {day}, part {}, {} failed on a random input (seed {seed}): {error}",
+                                entry.part, entry.implementation
+                            );
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
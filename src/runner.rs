@@ -0,0 +1,172 @@
+use std::io::{self, BufRead};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::input;
+use crate::memory::{self, Usage};
+use crate::profile;
+use crate::registry::{self, Entry};
+use crate::solver::Part;
+
+/// Outcome of solving one part of a day's problem.
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    // Not known if no solver is registered for the part.
+    pub implementation: Option<&'static str>,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+    // Part of `elapsed` spent reading and parsing the input, if known, see `profile::time`.
+    pub parse_elapsed: Option<Duration>,
+    // Heap usage while reading the input and solving it, if measured, see `memory::measure`.
+    pub memory: Option<Usage>,
+}
+
+/// Where a day's input is read from, once per part solved.
+struct Source<'a> {
+    year: u16,
+    day: u8,
+    path: Option<&'a Path>,
+    display_path: PathBuf,
+    // Stdin can only be read once, so it is read whole up front if solved more than once.
+    stdin: Option<io::Result<String>>,
+}
+
+impl<'a> Source<'a> {
+    fn new(year: u16, day: u8, path: Option<&'a Path>, num_runs: usize) -> Self {
+        Self {
+            year,
+            day,
+            path,
+            display_path: input::display_path(year, day, path),
+            stdin: (input::is_stdin(path) && num_runs > 1).then(|| input::read(year, day, path)),
+        }
+    }
+
+    fn open(&self) -> Result<Box<dyn BufRead + '_>> {
+        let reader: io::Result<Box<dyn BufRead>> = match &self.stdin {
+            Some(Ok(input)) => Ok(Box::new(input.as_bytes())),
+            // `io::Error` is not `Clone`, so every run gets its own copy.
+            Some(Err(source)) => Err(io::Error::new(source.kind(), source.to_string())),
+            None => input::open(self.year, self.day, self.path),
+        };
+        reader.map_err(|source| Error::Io {
+            path: self.display_path.clone(),
+            source,
+        })
+    }
+}
+
+/// Solves the given parts of a day's problem one after the other, with the named
+/// implementation, or the default one. A panicking solver does not abort the
+/// other runs, it is instead reported as a failed run.
+pub fn run(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    implementation: Option<&str>,
+    input_path: Option<&Path>,
+) -> Vec<Run> {
+    let source = Source::new(year, day, input_path, parts.len());
+
+    parts
+        .iter()
+        .map(
+            |&part| match registry::find(year, day, part, implementation) {
+                Some(entry) => solve(entry, &source),
+                None => {
+                    let available: Vec<_> = registry::implementations(year, day, part)
+                        .map(|entry| entry.implementation)
+                        .collect();
+                    let error = match implementation {
+                        Some(name) if !available.is_empty() => Error::UnknownImplementation {
+                            name: name.to_owned(),
+                            available,
+                        },
+                        _ => Error::NotRegistered,
+                    };
+                    Run {
+                        year,
+                        day,
+                        part,
+                        implementation: None,
+                        answer: Err(error),
+                        elapsed: Duration::ZERO,
+                        parse_elapsed: None,
+                        memory: None,
+                    }
+                }
+            },
+        )
+        .collect()
+}
+
+/// Solves both parts of a day's problem with every registered implementation,
+/// or only with the named one.
+pub fn run_implementations(
+    year: u16,
+    day: u8,
+    implementation: Option<&str>,
+    input_path: Option<&Path>,
+) -> Vec<Run> {
+    let entries: Vec<_> = Part::ALL
+        .iter()
+        .flat_map(|&part| registry::implementations(year, day, part))
+        .filter(|entry| implementation.is_none_or(|name| entry.implementation == name))
+        .collect();
+    let source = Source::new(year, day, input_path, entries.len());
+
+    entries
+        .into_iter()
+        .map(|entry| solve(entry, &source))
+        .collect()
+}
+
+fn solve(entry: &'static Entry, source: &Source) -> Run {
+    let run = profile_run(entry, || {
+        let mut reader = source.open()?;
+        catch_panic(|| entry.solve_reader(&mut reader))
+    });
+
+    Run {
+        answer: run
+            .answer
+            .map_err(|error| error.in_file(&source.display_path)),
+        ..run
+    }
+}
+
+/// Solves the given input with a single implementation, catching its panics.
+pub fn run_entry(entry: &'static Entry, input: &str) -> Run {
+    profile_run(entry, || catch_panic(|| entry.solve(input)))
+}
+
+fn profile_run(entry: &'static Entry, solve: impl FnOnce() -> Result<Answer>) -> Run {
+    let ((answer, timing), memory) = memory::measure(|| profile::time(solve));
+
+    Run {
+        year: entry.year,
+        day: entry.day,
+        part: entry.part,
+        implementation: Some(entry.implementation),
+        answer,
+        elapsed: timing.elapsed,
+        parse_elapsed: timing.parse,
+        memory,
+    }
+}
+
+fn catch_panic(solve: impl FnOnce() -> Result<Answer>) -> Result<Answer> {
+    panic::catch_unwind(AssertUnwindSafe(solve)).unwrap_or_else(|payload| {
+        let reason = payload
+            .downcast_ref::<&str>()
+            .map(|reason| reason.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        Err(Error::Panic(reason))
+    })
+}
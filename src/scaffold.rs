@@ -0,0 +1,269 @@
+//! Creates the module of a new day, and registers its solver.
+
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+// Where `register_years!` is invoked, relative to the root of the repository.
+const LIB_PATH: &str = "src/lib.rs";
+
+fn year_path(year: u16) -> PathBuf {
+    PathBuf::from(format!("src/year{year}/mod.rs"))
+}
+
+fn year_template(year: u16) -> String {
+    format!(
+        "//! Solvers of the [Advent Of Code {year}](https://adventofcode.com/{year}) problems.\n\n\
+         register_days! {{\n}}\n"
+    )
+}
+
+fn solver_template(year: u16, day: u8) -> String {
+    format!(
+        r#"use crate::answer::Answer;
+use crate::error::Result;
+use crate::parse;
+use crate::solver::Solver;
+
+pub struct Day{day}Solver {{}}
+
+impl Solver for Day{day}Solver {{
+    const YEAR: u16 = {year};
+    const DAY: u8 = {day};
+
+    // Placeholder: counts the lines of the input.
+    fn solve_part1(input: &str) -> Result<Answer> {{
+        Ok(parse::lines(input).count().into())
+    }}
+
+    // Placeholder: counts the lines of the input.
+    fn solve_part2(input: &str) -> Result<Answer> {{
+        Ok(parse::lines(input).count().into())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    #[test]
+    fn solves_examples() {{
+        let example = include_str!("small.txt");
+        assert_eq!(
+            Day{day}Solver::solve_part1(example).unwrap(),
+            Answer::Integer(0)
+        );
+        assert_eq!(
+            Day{day}Solver::solve_part2(example).unwrap(),
+            Answer::Integer(0)
+        );
+    }}
+}}
+"#
+    )
+}
+
+/// Creates `src/year[Y]/day[X]/` under `root`, with a placeholder solver, test
+/// module, and empty `input.txt` and `small.txt`, then declares the day's module
+/// and registers the solver in the year's invocation of `register_days!`. The
+/// year's module is created, declared and registered too if needed. Nothing is written if the day already exists.
+/// Returns the paths of the files written.
+pub fn new_day(root: &Path, year: u16, day: u8) -> io::Result<Vec<PathBuf>> {
+    let day_dir = root.join(input::day_dir(year, day));
+    if day_dir.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", day_dir.display()),
+        ));
+    }
+    let invalid_data = |reason| io::Error::new(ErrorKind::InvalidData, reason);
+
+    // Every registration is prepared before writing anything.
+    let mut updates = Vec::new();
+    let year_path = root.join(year_path(year));
+    let year_module = if year_path.exists() {
+        fs::read_to_string(&year_path)?
+    } else {
+        let lib_path = root.join(LIB_PATH);
+        let lib = fs::read_to_string(&lib_path)?;
+        let lib = declare(&lib, "register_years!", &format!("year{year}")).map_err(invalid_data)?;
+        let item = format!("year{year},");
+        updates.push((
+            lib_path,
+            register(&lib, "register_years!", &item).map_err(invalid_data)?,
+        ));
+        year_template(year)
+    };
+    let year_module =
+        declare(&year_module, "register_days!", &format!("day{day}")).map_err(invalid_data)?;
+    let item = format!("day{day}::Day{day}Solver,");
+    updates.push((
+        year_path,
+        register(&year_module, "register_days!", &item).map_err(invalid_data)?,
+    ));
+
+    fs::create_dir_all(&day_dir)?;
+    let mut written = Vec::new();
+    for (name, contents) in [
+        ("mod.rs", solver_template(year, day)),
+        ("input.txt", String::new()),
+        ("small.txt", String::new()),
+    ] {
+        let path = day_dir.join(name);
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+    for (path, contents) in updates {
+        fs::write(&path, contents)?;
+        written.push(path);
+    }
+
+    Ok(written)
+}
+
+/// Declares the module `name`, e.g. `day3`, among the `pub mod` lines right above
+/// the invocation of the macro in `module`, keeping them sorted like rustfmt does.
+fn declare(module: &str, macro_name: &str, name: &str) -> Result<String, String> {
+    let invocation = module
+        .find(&format!("{macro_name} {{\n"))
+        .ok_or_else(|| format!("no invocation of `{macro_name}` found"))?;
+    let declaration = format!("pub mod {name};");
+
+    let mut lines: Vec<_> = module[..invocation].lines().collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    let block_start = lines
+        .iter()
+        .rposition(|line| !(line.starts_with("pub mod ") && line.ends_with(';')))
+        .map_or(0, |i| i + 1);
+    let mut declarations = lines.split_off(block_start);
+    if declarations.contains(&declaration.as_str()) {
+        return Err(format!("`{name}` is already declared"));
+    }
+    declarations.push(&declaration);
+    // Sorted by name, so that `day1` comes before `day12`.
+    declarations.sort_unstable_by_key(|line| line.trim_end_matches(';'));
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+
+    let mut head: String = lines.iter().map(|line| format!("{line}\n")).collect();
+    if !lines.is_empty() {
+        head.push('\n');
+    }
+    let declarations: String = declarations
+        .iter()
+        .map(|line| format!("{line}\n"))
+        .collect();
+    Ok(format!("{head}{declarations}\n{}", &module[invocation..]))
+}
+
+/// Adds the item, e.g. `day3::Day3Solver,`, to the invocation of the macro in
+/// `module`, keeping the items ordered by the number they start with.
+fn register(module: &str, macro_name: &str, item: &str) -> Result<String, String> {
+    let invocation = format!("{macro_name} {{\n");
+    let start = module
+        .find(&invocation)
+        .ok_or_else(|| format!("no invocation of `{macro_name}` found"))?
+        + invocation.len();
+    let length = module[start..]
+        .find('}')
+        .ok_or_else(|| format!("the invocation of `{macro_name}` is not closed"))?;
+
+    let mut registrations: Vec<_> = module[start..start + length]
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_owned)
+        .collect();
+    if registrations
+        .iter()
+        .any(|registration| registration == item)
+    {
+        return Err(format!("`{item}` is already registered"));
+    }
+    registrations.push(item.to_owned());
+    registrations.sort_by_key(|registration| {
+        let number: String = registration
+            .trim_start_matches(char::is_alphabetic)
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .collect();
+        number.parse::<u16>().ok()
+    });
+
+    let body: String = registrations
+        .iter()
+        .map(|registration| format!("    {registration}\n"))
+        .collect();
+    Ok(format!(
+        "{}{body}{}",
+        &module[..start],
+        &module[start + length..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_keeps_days_in_order() {
+        let module =
+            "//! 2022.\n\nregister_days! {\n    day1::Day1Solver,\n    day12::Day12Solver,\n}\n";
+        assert_eq!(
+            register(module, "register_days!", "day3::Day3Solver,").unwrap(),
+            "//! 2022.\n\nregister_days! {\n    day1::Day1Solver,\n    day3::Day3Solver,\n    day12::Day12Solver,\n}\n"
+        );
+        assert!(register(module, "register_days!", "day12::Day12Solver,").is_err());
+        assert!(register(module, "register_years!", "year2021,").is_err());
+    }
+
+    #[test]
+    fn declare_keeps_modules_sorted_like_rustfmt() {
+        let module = "//! 2022.\n\npub mod day1;\npub mod day2;\n\nregister_days! {\n}\n";
+        assert_eq!(
+            declare(module, "register_days!", "day12").unwrap(),
+            "//! 2022.\n\npub mod day1;\npub mod day12;\npub mod day2;\n\nregister_days! {\n}\n"
+        );
+        assert!(declare(module, "register_days!", "day2").is_err());
+        assert_eq!(
+            declare("register_days! {\n}\n", "register_days!", "day1").unwrap(),
+            "pub mod day1;\n\nregister_days! {\n}\n"
+        );
+    }
+
+    #[test]
+    fn new_day_refuses_to_overwrite() {
+        let root = std::env::temp_dir().join(format!("scaffold-test-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join(LIB_PATH),
+            "pub mod answer;\n\npub mod year2022;\n\nregister_years! {\n    year2022,\n}\n",
+        )
+        .unwrap();
+
+        // The year's module is created along with its 1st day.
+        let written = new_day(&root, 2021, 2).unwrap();
+        assert_eq!(written.len(), 5);
+        assert_eq!(
+            fs::read_to_string(root.join(LIB_PATH)).unwrap(),
+            "pub mod answer;\n\npub mod year2021;\npub mod year2022;\n\nregister_years! {\n    year2021,\n    year2022,\n}\n"
+        );
+        let written = new_day(&root, 2021, 1).unwrap();
+        assert_eq!(written.len(), 4);
+        assert!(fs::read_to_string(root.join(year_path(2021)))
+            .unwrap()
+            .contains("pub mod day1;\npub mod day2;\n\nregister_days! {\n    day1::Day1Solver,\n    day2::Day2Solver,\n}"));
+
+        let input_path = root.join("src/year2021/day2/input.txt");
+        fs::write(&input_path, "kept").unwrap();
+        let error = new_day(&root, 2021, 2).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read_to_string(&input_path).unwrap(), "kept");
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
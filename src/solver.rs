@@ -1,13 +1,91 @@
-use crate::args::Part;
+use rand::rngs::StdRng;
+use std::io::{self, BufRead};
 
-pub trait Solver {
-    fn solve(part: Part) {
-        match part {
-            Part::Part1 => Self::solve_part1(),
-            Part::Part2 => Self::solve_part2(),
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::registry::{Entry, Solve};
+
+/// Every day's problem is split in two parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    Part1,
+    Part2,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::Part1, Part::Part2];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Self::Part1 => 1,
+            Self::Part2 => 2,
         }
     }
+}
+
+impl std::fmt::Display for Part {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(&self.number().to_string())
+    }
+}
+
+/// Solves both parts of a day's problem.
+pub trait Solver {
+    /// Year and day of the problem, used to find its default input.
+    const YEAR: u16;
+    const DAY: u8;
+
+    /// Whether both parts read their input incrementally, see `solve_part1_streaming`.
+    const STREAMING: bool = false;
 
-    fn solve_part1();
-    fn solve_part2();
+    /// Solvers to register for this day's problem.
+    fn entries() -> Vec<Entry> {
+        let solver = |whole, streaming| match Self::STREAMING {
+            true => Solve::Streaming(streaming),
+            false => Solve::Whole(whole),
+        };
+
+        vec![
+            Entry {
+                year: Self::YEAR,
+                day: Self::DAY,
+                part: Part::Part1,
+                implementation: "default",
+                solver: solver(Self::solve_part1, Self::solve_part1_streaming),
+            },
+            Entry {
+                year: Self::YEAR,
+                day: Self::DAY,
+                part: Part::Part2,
+                implementation: "default",
+                solver: solver(Self::solve_part2, Self::solve_part2_streaming),
+            },
+        ]
+    }
+
+    /// Generates a random, well-formed puzzle input holding about `size` items
+    /// (e.g. lines or characters), or `None` if the day does not support it.
+    fn generate_input(_rng: &mut StdRng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Draws what solving a part computes from the input, e.g. a path over a map,
+    /// or returns `None` if the day does not support it. Unlike answers, drawings
+    /// are neither verified nor cross-checked.
+    fn visualize(_input: &str, _part: Part) -> Option<Result<Answer>> {
+        None
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer>;
+    fn solve_part2(input: &str) -> Result<Answer>;
+
+    /// Solves the 1st part while reading its input, only holding in memory what
+    /// the solver needs. Days setting `STREAMING` must implement both parts' ones.
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        Self::solve_part1(&io::read_to_string(reader)?)
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        Self::solve_part2(&io::read_to_string(reader)?)
+    }
 }
@@ -0,0 +1,167 @@
+//! Resolves the puzzle inputs, and their known-good answers, from a directory
+//! kept apart from the code, so that every user can solve their own inputs.
+//!
+//! The store's root holds a file per year, day and user profile, e.g.
+//! `<root>/2022/9/alice.txt`, along with `<root>/2022/9/alice.answers.txt`.
+//! Without a root, the inputs checked in alongside the solvers are used.
+
+use serde::Deserialize;
+use std::env;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::input;
+
+/// Environment variables configuring the store, which take precedence over the config file.
+pub const ROOT_VAR: &str = "AOC_INPUT_ROOT";
+pub const USER_VAR: &str = "AOC_USER";
+/// Environment variable holding the path of the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// Config file read when `AOC_CONFIG` is not set, relative to the working directory.
+pub const DEFAULT_CONFIG_PATH: &str = "advent-of-code.json";
+/// Profile used when no user is configured.
+pub const DEFAULT_USER: &str = "default";
+
+static STORE: OnceLock<Store> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Store {
+    // Inputs are checked in alongside the solvers if there is no root.
+    root: Option<PathBuf>,
+    user: String,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Config {
+    input_root: Option<PathBuf>,
+    user: Option<String>,
+}
+
+impl Store {
+    /// The inputs checked in alongside the solvers, at `src/year[Y]/day[X]/input.txt`.
+    pub fn checked_in() -> Self {
+        Self {
+            root: None,
+            user: DEFAULT_USER.to_owned(),
+        }
+    }
+
+    pub fn new(root: impl Into<PathBuf>, user: impl Into<String>) -> Self {
+        Self {
+            root: Some(root.into()),
+            user: user.into(),
+        }
+    }
+
+    /// Configures the store from the environment variables, then from the
+    /// config file if it exists. A relative root in the config file is relative
+    /// to the config file itself.
+    pub fn from_env() -> io::Result<Self> {
+        let config_path = env::var_os(CONFIG_VAR);
+        let config = match &config_path {
+            Some(path) => Some(read_config(Path::new(path))?),
+            None => match read_config(Path::new(DEFAULT_CONFIG_PATH)) {
+                Err(error) if error.kind() == ErrorKind::NotFound => None,
+                config => Some(config?),
+            },
+        };
+        let config_dir = config_path.as_deref().map_or(Path::new(""), |path| {
+            Path::new(path).parent().unwrap_or(Path::new(""))
+        });
+        let (config_root, config_user) = config
+            .map(|config| (config.input_root, config.user))
+            .unwrap_or_default();
+
+        Ok(Self {
+            root: env::var_os(ROOT_VAR)
+                .map(PathBuf::from)
+                .or_else(|| config_root.map(|root| config_dir.join(root))),
+            user: env::var(USER_VAR)
+                .ok()
+                .or(config_user)
+                .unwrap_or_else(|| DEFAULT_USER.to_owned()),
+        })
+    }
+
+    /// Reads the inputs of another user's profile. Only stores with a root hold profiles.
+    pub fn with_user(self, user: impl Into<String>) -> Option<Self> {
+        self.root.is_some().then(|| Self {
+            user: user.into(),
+            ..self
+        })
+    }
+
+    /// Path of the user's input for the given day.
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        match &self.root {
+            Some(root) => root
+                .join(year.to_string())
+                .join(day.to_string())
+                .join(format!("{}.txt", self.user)),
+            None => input::day_dir(year, day).join("input.txt"),
+        }
+    }
+
+    /// Path of the known-good answers to the user's input for the given day,
+    /// see `verify::verify`.
+    pub fn answers_path(&self, year: u16, day: u8) -> PathBuf {
+        match &self.root {
+            Some(root) => root
+                .join(year.to_string())
+                .join(day.to_string())
+                .join(format!("{}.answers.txt", self.user)),
+            None => input::day_dir(year, day).join("answers.txt"),
+        }
+    }
+}
+
+fn read_config(path: &Path) -> io::Result<Config> {
+    let config = fs::read_to_string(path)?;
+    serde_json::from_str(&config).map_err(|error| {
+        io::Error::new(
+            ErrorKind::InvalidData,
+            format!("invalid config file {}: {error}", path.display()),
+        )
+    })
+}
+
+/// Uses the given store to find every input read without an explicit path.
+/// Only the first store installed is used, and returned back otherwise.
+pub fn install(store: Store) -> Result<(), Store> {
+    STORE.set(store)
+}
+
+/// The installed store, or the checked-in inputs if none was installed.
+pub fn current() -> &'static Store {
+    STORE.get_or_init(Store::checked_in)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_follow_the_layout() {
+        let store = Store::new("inputs", "alice");
+        assert_eq!(
+            store.input_path(2022, 9),
+            Path::new("inputs/2022/9/alice.txt")
+        );
+        assert_eq!(
+            store.answers_path(2022, 9),
+            Path::new("inputs/2022/9/alice.answers.txt")
+        );
+        let bob = store.with_user("bob").unwrap();
+        assert_eq!(bob.input_path(2022, 9), Path::new("inputs/2022/9/bob.txt"));
+
+        let checked_in = Store::checked_in();
+        assert_eq!(
+            checked_in.input_path(2022, 9),
+            Path::new("src/year2022/day9/input.txt")
+        );
+        assert!(checked_in.with_user("bob").is_none());
+    }
+}
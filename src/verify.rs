@@ -0,0 +1,245 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::error::{Error, ParseResult, Result};
+use crate::parse;
+use crate::registry;
+use crate::runner;
+use crate::solver::Part;
+use crate::store;
+
+/// Path of the known-good answers to the day's input in the installed input store,
+/// which defaults to the answers checked in alongside the day's solver.
+///
+/// The file holds a `part1:` line followed by the lines of the 1st part's answer,
+/// and likewise for the 2nd part.
+pub fn answers_path(year: u16, day: u8) -> PathBuf {
+    store::current().answers_path(year, day)
+}
+
+// A day without an answers file has no known-good answer for either part.
+fn read_answers(year: u16, day: u8) -> Result<HashMap<Part, String>> {
+    let path = answers_path(year, day);
+    let file = match std::fs::read_to_string(&path) {
+        Ok(file) => file,
+        Err(source) if source.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(source) => return Err(Error::Io { path, source }),
+    };
+    parse_answers(&file).map_err(|error| Error::from(error).in_file(&path))
+}
+
+fn parse_answers(file: &str) -> ParseResult<HashMap<Part, String>> {
+    let mut answers: HashMap<Part, Vec<&str>> = HashMap::new();
+    let mut curr_part = None;
+
+    for line in parse::lines(file) {
+        let part = match line.text {
+            "part1:" => Part::Part1,
+            "part2:" => Part::Part2,
+            text => {
+                let part = curr_part.ok_or_else(|| line.error("a `part1:` or `part2:` line"))?;
+                answers.entry(part).or_default().push(text);
+                continue;
+            }
+        };
+
+        answers.insert(part, Vec::new());
+        curr_part = Some(part);
+    }
+
+    Ok(answers
+        .into_iter()
+        .map(|(part, lines)| (part, lines.join("\n")))
+        .collect())
+}
+
+pub enum Outcome {
+    Matched,
+    Mismatched { expected: String, actual: String },
+    // No known-good answer is checked in for this part, or for the whole day.
+    Missing,
+    Failed(Error),
+}
+
+pub struct Verification {
+    pub day: u8,
+    pub part: Part,
+    pub implementation: &'static str,
+    pub outcome: Outcome,
+}
+
+/// Solves both parts of a day's problem against its input from the input store, with every
+/// implementation or only the named one, and compares the answers with the
+/// known-good ones. Fails if the known-good answers cannot be read.
+pub fn verify(year: u16, day: u8, implementation: Option<&str>) -> Result<Vec<Verification>> {
+    let expected_answers = read_answers(year, day)?;
+
+    Ok(runner::run_implementations(year, day, implementation, None)
+        .into_iter()
+        .map(|run| {
+            let outcome = match (run.answer, expected_answers.get(&run.part)) {
+                (Err(reason), _) => Outcome::Failed(reason),
+                (Ok(_), None) => Outcome::Missing,
+                (Ok(answer), Some(expected)) => {
+                    let actual = answer.to_string();
+                    if actual == *expected {
+                        Outcome::Matched
+                    } else {
+                        Outcome::Mismatched {
+                            expected: expected.clone(),
+                            actual,
+                        }
+                    }
+                }
+            };
+
+            Verification {
+                day,
+                part: run.part,
+                // Only runs of registered implementations are returned.
+                implementation: run.implementation.unwrap(),
+                outcome,
+            }
+        })
+        .collect())
+}
+
+/// Verifies every day of the year, see `verify`, and writes whether each answer
+/// matched to `out` as soon as its day is verified, along with a diff of every
+/// mismatched answer. Returns whether every answer matched.
+pub fn verify_all(
+    year: u16,
+    implementation: Option<&str>,
+    out: &mut impl Write,
+) -> io::Result<bool> {
+    let mut all_matched = true;
+    for day in registry::days(year) {
+        all_matched &= report(year, day, verify(year, day, implementation), out)?;
+    }
+    Ok(all_matched)
+}
+
+// Writes the verifications of a day, and returns whether every answer matched.
+fn report(
+    year: u16,
+    day: u8,
+    verifications: Result<Vec<Verification>>,
+    out: &mut impl Write,
+) -> io::Result<bool> {
+    let verifications = match verifications {
+        Ok(verifications) => verifications,
+        Err(error) => {
+            writeln!(out, "Day {day}: FAILED ({error})")?;
+            return Ok(false);
+        }
+    };
+
+    let mut all_matched = true;
+    for verification in verifications {
+        let status = match &verification.outcome {
+            Outcome::Matched => "ok".to_owned(),
+            Outcome::Mismatched { .. } => "MISMATCH".to_owned(),
+            Outcome::Missing => "no known-good answer".to_owned(),
+            Outcome::Failed(error) => format!("FAILED ({error})"),
+        };
+        // Implementations are only worth naming when the part has alternatives.
+        let alternatives = registry::implementations(year, day, verification.part).count();
+        let label = if alternatives > 1 {
+            format!(" ({})", verification.implementation)
+        } else {
+            String::new()
+        };
+        writeln!(
+            out,
+            "Day {day}, part {}{label}: {status}",
+            verification.part
+        )?;
+
+        match verification.outcome {
+            Outcome::Matched => {}
+            Outcome::Mismatched { expected, actual } => {
+                write!(out, "{}", diff(&expected, &actual))?;
+                all_matched = false;
+            }
+            Outcome::Missing | Outcome::Failed(_) => all_matched = false,
+        }
+    }
+
+    Ok(all_matched)
+}
+
+/// A line by line diff, with expected lines prefixed by `-` and actual lines by `+`.
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(expected), Some(actual)) if expected == actual => {
+                diff += &format!("     {expected}\n");
+            }
+            (expected, actual) => {
+                if let Some(expected) = expected {
+                    diff += &format!("   - {expected}\n");
+                }
+                if let Some(actual) = actual {
+                    diff += &format!("   + {actual}\n");
+                }
+            }
+        }
+    }
+    diff
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers_of_several_lines() {
+        let answers = parse_answers("part1:\n13\npart2:\n##..\n..##").unwrap();
+        assert_eq!(answers[&Part::Part1], "13");
+        assert_eq!(answers[&Part::Part2], "##..\n..##");
+
+        let error = parse_answers("13\npart2:\n36").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+    }
+
+    #[test]
+    fn reports_mismatched_and_missing_answers() {
+        let verification = |part, outcome| Verification {
+            day: 10,
+            part,
+            implementation: "default",
+            outcome,
+        };
+        let verifications = vec![
+            verification(
+                Part::Part1,
+                Outcome::Mismatched {
+                    expected: "#.\n.#".to_owned(),
+                    actual: "#.\n##".to_owned(),
+                },
+            ),
+            verification(Part::Part2, Outcome::Missing),
+        ];
+
+        let mut out = Vec::new();
+        assert!(!report(2022, 10, Ok(verifications), &mut out).unwrap());
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Day 10, part 1: MISMATCH\n     #.\n   - .#\n   + ##\nDay 10, part 2: no known-good answer\n"
+        );
+    }
+
+    #[test]
+    fn reports_unreadable_answers() {
+        let mut out = Vec::new();
+        let error = Error::NoSolution("no answers".to_owned());
+        assert!(!report(2022, 10, Err(error), &mut out).unwrap());
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .starts_with("Day 10: FAILED"));
+    }
+}
@@ -0,0 +1,189 @@
+//! Polls files for changes, without relying on OS-specific notifications, to
+//! solve the input again every time it changes.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::input;
+use crate::runner::{self, Run};
+use crate::solver::Part;
+
+/// What a file looked like when last polled. A missing file has no stamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Stamp {
+    modified: SystemTime,
+    length: u64,
+}
+
+impl Stamp {
+    fn of(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            modified: metadata.modified().ok()?,
+            length: metadata.len(),
+        })
+    }
+}
+
+/// Watches a set of files, by comparing their modification time and length
+/// every time they are polled.
+pub struct Watcher {
+    files: Vec<(PathBuf, Option<Stamp>)>,
+}
+
+impl Watcher {
+    pub fn new(paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        Self {
+            files: paths
+                .into_iter()
+                .map(|path| {
+                    let stamp = Stamp::of(&path);
+                    (path, stamp)
+                })
+                .collect(),
+        }
+    }
+
+    /// The watched files which changed, appeared or disappeared since the last poll.
+    pub fn poll(&mut self) -> Vec<&Path> {
+        self.files
+            .iter_mut()
+            .filter_map(|(path, stamp)| {
+                let new_stamp = Stamp::of(path);
+                (new_stamp != *stamp).then(|| {
+                    *stamp = new_stamp;
+                    path.as_path()
+                })
+            })
+            .collect()
+    }
+}
+
+/// Solves the given parts every time the input changes, checking for changes
+/// every `interval`, until interrupted or writing the summaries to `out` fails.
+pub fn watch(
+    year: u16,
+    day: u8,
+    parts: &[Part],
+    implementation: Option<&str>,
+    input_path: Option<&Path>,
+    interval: Duration,
+    out: &mut impl Write,
+) -> io::Error {
+    let input_file = input_path.map_or_else(|| input::default_path(year, day), Path::to_path_buf);
+    // The binary cannot pick up changes to the solver, but they are worth pointing out.
+    let source_file = input::day_dir(year, day).join("mod.rs");
+    let mut watcher = Watcher::new([input_file.clone(), source_file.clone()]);
+    eprintln!("Watching {}, press Ctrl-C to stop.", input_file.display());
+
+    let mut previous_runs: Vec<Run> = Vec::new();
+    loop {
+        let runs = runner::run(year, day, parts, implementation, input_path);
+        for (i, run) in runs.iter().enumerate() {
+            if let Err(error) = writeln!(out, "{}", summarize(run, previous_runs.get(i))) {
+                return error;
+            }
+            if let Err(error) = &run.answer {
+                eprintln!("{}", error.diagnostic());
+            }
+        }
+        previous_runs = runs;
+
+        loop {
+            thread::sleep(interval);
+            let changed = watcher.poll();
+            if changed.contains(&source_file.as_path()) {
+                eprintln!(
+                    "{} changed, restart `watch` to solve with the new code.",
+                    source_file.display()
+                );
+            }
+            if changed.contains(&input_file.as_path()) {
+                if let Err(error) = writeln!(out) {
+                    return error;
+                }
+                break;
+            }
+        }
+    }
+}
+
+/// The answer and timing of a run, and how they changed since the previous one.
+pub fn summarize(run: &Run, previous_run: Option<&Run>) -> String {
+    let mut summary = format!("Day {}, part {} in {:.2?}", run.day, run.part, run.elapsed);
+    if let Some(previous_run) = previous_run {
+        let sign = if run.elapsed >= previous_run.elapsed {
+            '+'
+        } else {
+            '-'
+        };
+        summary += &format!(
+            " ({sign}{:.2?})",
+            run.elapsed.abs_diff(previous_run.elapsed)
+        );
+        if run.answer.is_ok() && previous_run.answer.is_ok() {
+            let changed = run.answer.as_ref().ok() != previous_run.answer.as_ref().ok();
+            summary += if changed {
+                ", answer changed"
+            } else {
+                ", answer unchanged"
+            };
+        }
+    }
+
+    match &run.answer {
+        Ok(answer) => {
+            let answer = answer.to_string();
+            let separator = if answer.contains('\n') { ":\n" } else { ": " };
+            format!("{summary}{separator}{answer}")
+        }
+        Err(_) => format!("{summary}: FAILED"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn poll_reports_changed_files() {
+        let path = std::env::temp_dir().join(format!("watch-test-{}.txt", std::process::id()));
+        fs::write(&path, "1").unwrap();
+        let mut watcher = Watcher::new([path.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        // The length changes too, in case the modification time is too coarse.
+        fs::write(&path, "12").unwrap();
+        assert_eq!(watcher.poll(), [path.as_path()]);
+        assert!(watcher.poll().is_empty());
+
+        fs::remove_file(&path).unwrap();
+        assert_eq!(watcher.poll(), [path.as_path()]);
+    }
+
+    #[test]
+    fn summaries_compare_with_the_previous_run() {
+        let run = |answer: i64, millis| Run {
+            year: 2022,
+            day: 1,
+            part: Part::Part1,
+            implementation: Some("default"),
+            answer: Ok(answer.into()),
+            elapsed: Duration::from_millis(millis),
+            parse_elapsed: None,
+            memory: None,
+        };
+        assert_eq!(summarize(&run(24, 5), None), "Day 1, part 1 in 5.00ms: 24");
+        assert_eq!(
+            summarize(&run(24, 3), Some(&run(24, 5))),
+            "Day 1, part 1 in 3.00ms (-2.00ms), answer unchanged: 24"
+        );
+        assert_eq!(
+            summarize(&run(25, 5), Some(&run(24, 3))),
+            "Day 1, part 1 in 5.00ms (+2.00ms), answer changed: 25"
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{Error, Result};
+use crate::parse;
+use crate::solver::Solver;
+
+pub struct Day1Solver {}
+
+impl Day1Solver {
+    // Elves carry `i32` calories per snack, but can carry many snacks.
+    fn add_calories(energy: i64, calories: i64) -> Result<i64> {
+        energy
+            .checked_add(calories)
+            .ok_or_else(|| Error::NoSolution("calories do not fit in 64 bits".to_owned()))
+    }
+
+    // Keeps the largest energies, in decreasing order.
+    fn record_energy(top_energies: &mut [i64], energy: i64) {
+        if let Some(i) = top_energies.iter().position(|&top| energy > top) {
+            top_energies[i..].rotate_right(1);
+            top_energies[i] = energy;
+        }
+    }
+}
+
+impl Solver for Day1Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    // `size` is the number of elves, each carrying a handful of snacks.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let elves: Vec<_> = (0..size)
+            .map(|_| {
+                let snacks: Vec<_> = (0..rng.gen_range(1..=10))
+                    .map(|_| rng.gen_range(1_000..=70_000).to_string())
+                    .collect();
+                snacks.join("\n")
+            })
+            .collect();
+        Some(elves.join("\n\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut max_energy = 0;
+        let mut curr_energy = 0;
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            if line.text.is_empty() {
+                max_energy = max_energy.max(curr_energy);
+                curr_energy = 0;
+                continue;
+            }
+
+            let calories = line.token().parse::<i32>("a number of calories")?;
+            curr_energy = Self::add_calories(curr_energy, calories.into())?;
+        }
+
+        // The last elf is not followed by an empty line.
+        Ok(max_energy.max(curr_energy).into())
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut top_energies = [0; 3];
+        let mut curr_energy = 0;
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            if line.text.is_empty() {
+                Self::record_energy(&mut top_energies, curr_energy);
+                curr_energy = 0;
+                continue;
+            }
+
+            let calories = line.token().parse::<i32>("a number of calories")?;
+            curr_energy = Self::add_calories(curr_energy, calories.into())?;
+        }
+
+        // The last elf is not followed by an empty line.
+        Self::record_energy(&mut top_energies, curr_energy);
+        let total_energy = top_energies.into_iter().try_fold(0, Self::add_calories)?;
+        Ok(total_energy.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day1Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(24000)
+        );
+        assert_eq!(
+            Day1Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(45000)
+        );
+    }
+
+    #[test]
+    fn sums_calories_past_32_bits() {
+        let input = "2147483647\n1\n\n2147483647\n2147483647";
+        assert_eq!(
+            Day1Solver::solve_part1(input).unwrap(),
+            Answer::Integer(4294967294)
+        );
+        assert_eq!(
+            Day1Solver::solve_part2(input).unwrap(),
+            Answer::Integer(6442450942)
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::solver::Solver;
+
+pub const CRT_SCREEN_WIDTH: usize = 40;
+pub const CRT_SCREEN_HEIGHT: usize = 6;
+
+pub struct Day10Solver {}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+impl Instruction {
+    pub fn parse(instruction: Token) -> ParseResult<Self> {
+        if instruction.text == "noop" {
+            Ok(Self::Noop)
+        } else if let Some(v) = instruction.strip_prefix("addx ") {
+            Ok(Self::Addx(v.parse("an integer")?))
+        } else {
+            Err(instruction.error("a `noop` or `addx` instruction"))
+        }
+    }
+
+    // Parses the instructions while reading them. The iteration stops at the
+    // first malformed instruction, which is then stored in `error`.
+    fn read_all<'a>(
+        reader: &'a mut dyn BufRead,
+        error: &'a mut Option<Error>,
+    ) -> impl Iterator<Item = Self> + 'a {
+        parse::read_lines(reader).map_while(move |line| {
+            match line.and_then(|line| Ok(Self::parse(line.token())?)) {
+                Ok(instruction) => Some(instruction),
+                Err(malformed) => {
+                    *error = Some(malformed);
+                    None
+                }
+            }
+        })
+    }
+}
+
+const LAST_MONITORED_CYCLE: i64 = 220;
+const LAST_DRAWN_CYCLE: i64 = (CRT_SCREEN_WIDTH * CRT_SCREEN_HEIGHT) as i64;
+
+pub struct Program {
+    // Starts at 1, and increments every cycle.
+    cycle: i64,
+    // Current value in the `X` register. It is only updated while it can still be
+    // read, so that it cannot overflow however many instructions follow.
+    register: i64,
+    // Only used for part 1.
+    sum_signal_strengths: i64,
+    // Only used for part 2.
+    crt_screen: CrtScreen,
+}
+
+impl Default for Program {
+    fn default() -> Self {
+        Self {
+            cycle: 1,
+            register: 1,
+            sum_signal_strengths: 0,
+            crt_screen: CrtScreen::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum Pixel {
+    Lit,
+    Dark,
+}
+
+impl From<&Pixel> for char {
+    fn from(value: &Pixel) -> Self {
+        match *value {
+            Pixel::Lit => '#',
+            Pixel::Dark => '.',
+        }
+    }
+}
+
+pub struct CrtScreen {
+    pub screen: [[Pixel; CRT_SCREEN_WIDTH]; CRT_SCREEN_HEIGHT],
+}
+
+impl Default for CrtScreen {
+    fn default() -> Self {
+        Self {
+            screen: [[Pixel::Dark; CRT_SCREEN_WIDTH]; CRT_SCREEN_HEIGHT],
+        }
+    }
+}
+
+impl From<CrtScreen> for Answer {
+    fn from(value: CrtScreen) -> Self {
+        Answer::Grid(
+            value
+                .screen
+                .iter()
+                .map(|row| row.iter().map(char::from).collect())
+                .collect(),
+        )
+    }
+}
+
+impl Program {
+    pub fn compute_sum_signal_strengths(instructions: impl Iterator<Item = Instruction>) -> i64 {
+        let mut program = Self::default();
+
+        for instruction in instructions {
+            program.run_compute_sum_signal_strengths_cycle(None);
+
+            if let Instruction::Addx(v) = instruction {
+                program.run_compute_sum_signal_strengths_cycle(Some(v));
+            }
+        }
+
+        program.sum_signal_strengths
+    }
+
+    fn run_compute_sum_signal_strengths_cycle(&mut self, update_register: Option<i32>) {
+        if self.is_monitored_cycle() {
+            self.sum_signal_strengths += self.cycle * self.register;
+        }
+
+        self.cycle += 1;
+        if let Some(v) = update_register.filter(|_| self.cycle <= LAST_MONITORED_CYCLE) {
+            self.register += i64::from(v);
+        }
+    }
+
+    fn is_monitored_cycle(&self) -> bool {
+        matches!(self.cycle, 20 | 60 | 100 | 140 | 180 | 220)
+    }
+
+    pub fn run_crt(instructions: impl Iterator<Item = Instruction>) -> CrtScreen {
+        let mut program = Self::default();
+
+        for instruction in instructions {
+            program.run_crt_cycle(None);
+
+            if let Instruction::Addx(v) = instruction {
+                program.run_crt_cycle(Some(v));
+            }
+        }
+
+        program.crt_screen
+    }
+
+    fn run_crt_cycle(&mut self, update_register: Option<i32>) {
+        let row = (self.cycle - 1) as usize / CRT_SCREEN_WIDTH;
+        let col = (self.cycle - 1) % CRT_SCREEN_WIDTH as i64;
+
+        // Cycles after the screen is fully drawn are not displayed.
+        if row < CRT_SCREEN_HEIGHT
+            && (col == self.register - 1 || col == self.register || col == self.register + 1)
+        {
+            self.crt_screen.screen[row][col as usize] = Pixel::Lit;
+        }
+
+        self.cycle += 1;
+        if let Some(v) = update_register.filter(|_| self.cycle <= LAST_DRAWN_CYCLE) {
+            self.register += i64::from(v);
+        }
+    }
+}
+
+impl Solver for Day10Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    // `size` is the number of instructions.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let instructions: Vec<_> = (0..size)
+            .map(|_| match rng.gen_bool(0.5) {
+                true => "noop".to_owned(),
+                false => format!("addx {}", rng.gen_range(-20..=20)),
+            })
+            .collect();
+        Some(instructions.join("\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut error = None;
+        let instructions = Instruction::read_all(reader, &mut error);
+        let sum_signal_strengths = Program::compute_sum_signal_strengths(instructions);
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(sum_signal_strengths.into()),
+        }
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut error = None;
+        let instructions = Instruction::read_all(reader, &mut error);
+        let crt_screen = Program::run_crt(instructions);
+
+        match error {
+            Some(error) => Err(error),
+            None => Ok(crt_screen.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day10Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(13140)
+        );
+        assert_eq!(
+            Day10Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Grid(
+                [
+                    "##..##..##..##..##..##..##..##..##..##..",
+                    "###...###...###...###...###...###...###.",
+                    "####....####....####....####....####....",
+                    "#####.....#####.....#####.....#####.....",
+                    "######......######......######......####",
+                    "#######.......#######.......#######.....",
+                ]
+                .map(str::to_owned)
+                .to_vec()
+            )
+        );
+    }
+
+    #[test]
+    fn solves_registers_past_32_bits() {
+        let input = format!("addx 2147483647\naddx 2147483647{}", "\nnoop".repeat(240));
+        // The register holds 1 + 2 * 2147483647 from the 5th cycle on.
+        assert_eq!(
+            Day10Solver::solve_part1(&input).unwrap(),
+            Answer::Integer(720 * 4294967295)
+        );
+        assert!(Day10Solver::solve_part2(&input).is_ok());
+    }
+}
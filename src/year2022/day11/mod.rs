@@ -0,0 +1,385 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseResult, Result};
+use crate::parse::{self, Lines, Token};
+use crate::profile;
+use crate::solver::Solver;
+
+// Divisors of the generated monkeys' tests. Their product must stay below
+// 2^32, so that squaring a worry level reduced modulo it cannot overflow.
+const GENERATED_DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+pub struct Day11Solver {}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Operand {
+    Old,
+    Value(u64),
+}
+
+/// How a monkey computes the new worry level of an item from the `old` one.
+#[derive(Debug, Clone, Copy)]
+pub enum Operation {
+    Add(Operand),
+    Multiply(Operand),
+}
+
+impl Operation {
+    fn parse(operation: Token) -> ParseResult<Self> {
+        let (operator, operand) = operation.split_once(' ', "an operator and an operand")?;
+        let operand = match operand.text {
+            "old" => Operand::Old,
+            _ => Operand::Value(operand.parse("`old` or an integer")?),
+        };
+        match operator.text {
+            "+" => Ok(Self::Add(operand)),
+            "*" => Ok(Self::Multiply(operand)),
+            _ => Err(operator.error("`+` or `*`")),
+        }
+    }
+
+    // None if the new worry level overflows.
+    fn apply(&self, old: u64) -> Option<u64> {
+        let value = |operand| match operand {
+            Operand::Old => old,
+            Operand::Value(value) => value,
+        };
+        match *self {
+            Self::Add(operand) => old.checked_add(value(operand)),
+            Self::Multiply(operand) => old.checked_mul(value(operand)),
+        }
+    }
+
+    fn render(&self) -> String {
+        let (operator, operand) = match self {
+            Self::Add(operand) => ('+', operand),
+            Self::Multiply(operand) => ('*', operand),
+        };
+        match operand {
+            Operand::Old => format!("new = old {operator} old"),
+            Operand::Value(value) => format!("new = old {operator} {value}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Monkey {
+    // Worry levels of the items held, in the order they are inspected.
+    pub items: Vec<u64>,
+    pub operation: Operation,
+    pub divisor: u64,
+    // Monkeys the item is thrown to, whether its worry level is divisible or not.
+    pub if_divisible: usize,
+    pub if_not_divisible: usize,
+}
+
+// The next line of a monkey's description, without its indentation and `prefix`.
+fn expect_line<'a>(lines: &mut Lines<'a>, prefix: &str) -> ParseResult<Token<'a>> {
+    let expected = format!("a line starting with `{prefix}`");
+    let line = lines
+        .next()
+        .ok_or_else(|| lines.end_of_input(expected.as_str()))?;
+    line.trim_start()
+        .strip_prefix(prefix)
+        .ok_or_else(|| line.error(expected))
+}
+
+/// Parses the description of every monkey, which are separated by empty lines.
+pub fn parse_monkeys(input: &str) -> ParseResult<Vec<Monkey>> {
+    let mut lines = parse::lines(input);
+    let mut monkeys = Vec::new();
+    // Checked once the number of monkeys is known.
+    let mut targets = Vec::new();
+
+    while let Some(header) = lines.next() {
+        let index = header
+            .strip_prefix("Monkey ")
+            .and_then(|header| header.strip_suffix(":"))
+            .ok_or_else(|| header.error("a header like `Monkey 0:`"))?;
+        if index.parse::<usize>("a monkey index")? != monkeys.len() {
+            return Err(index.error(format!("monkey {}", monkeys.len())));
+        }
+
+        let items = expect_line(&mut lines, "Starting items: ")?;
+        let items = match items.text {
+            "" => Vec::new(),
+            _ => items
+                .split(", ")
+                .map(|item| item.parse("a worry level"))
+                .collect::<ParseResult<_>>()?,
+        };
+        let operation = Operation::parse(expect_line(&mut lines, "Operation: new = old ")?)?;
+        let divisor = expect_line(&mut lines, "Test: divisible by ")?;
+        let divisor = match divisor.parse("a positive divisor")? {
+            0 => return Err(divisor.error("a positive divisor")),
+            value => value,
+        };
+        let if_divisible = expect_line(&mut lines, "If true: throw to monkey ")?;
+        let if_not_divisible = expect_line(&mut lines, "If false: throw to monkey ")?;
+        for target in [if_divisible, if_not_divisible] {
+            targets.push((monkeys.len(), target));
+        }
+
+        monkeys.push(Monkey {
+            items,
+            operation,
+            divisor,
+            if_divisible: if_divisible.parse("a monkey index")?,
+            if_not_divisible: if_not_divisible.parse("a monkey index")?,
+        });
+
+        if let Some(separator) = lines.next() {
+            if !separator.text.is_empty() {
+                return Err(separator.error("an empty line between monkeys"));
+            }
+        }
+    }
+
+    for (monkey, target) in targets {
+        // A monkey throwing items to itself would never end its turn.
+        match target.parse::<usize>("a monkey index")? {
+            index if index < monkeys.len() && index != monkey => {}
+            _ => {
+                return Err(target.error(format!(
+                    "the index of another monkey, below {}",
+                    monkeys.len()
+                )))
+            }
+        }
+    }
+
+    Ok(monkeys)
+}
+
+/// What keeps worry levels from growing out of hand after an item is inspected.
+#[derive(Debug, Clone, Copy)]
+pub enum Relief {
+    // The worry level is divided by 3, as the item is not damaged.
+    DivideByThree,
+    // The worry level is only kept modulo the product of every divisor, which
+    // all tests still agree with.
+    Modulo(u64),
+}
+
+pub struct Troop {
+    pub monkeys: Vec<Monkey>,
+    // Number of items inspected by each monkey so far.
+    pub inspections: Vec<usize>,
+}
+
+impl Troop {
+    pub fn new(monkeys: Vec<Monkey>) -> Self {
+        let inspections = vec![0; monkeys.len()];
+        Self {
+            monkeys,
+            inspections,
+        }
+    }
+
+    /// The relief which keeps worry levels bounded without changing where items go.
+    /// Returns None if the product of the divisors overflows.
+    pub fn modular_relief(&self) -> Option<Relief> {
+        self.monkeys
+            .iter()
+            .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisor))
+            .map(Relief::Modulo)
+    }
+
+    /// Plays a round, where every monkey in turn inspects and throws all its items.
+    /// Returns None if a worry level overflows.
+    pub fn play_round(&mut self, relief: Relief) -> Option<()> {
+        for i in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[i].items);
+            self.inspections[i] += items.len();
+
+            let Monkey {
+                operation,
+                divisor,
+                if_divisible,
+                if_not_divisible,
+                ..
+            } = self.monkeys[i];
+            for item in items {
+                let worry = operation.apply(item)?;
+                let worry = match relief {
+                    Relief::DivideByThree => worry / 3,
+                    Relief::Modulo(modulus) => worry % modulus,
+                };
+                let target = match worry % divisor {
+                    0 => if_divisible,
+                    _ => if_not_divisible,
+                };
+                self.monkeys[target].items.push(worry);
+            }
+        }
+
+        Some(())
+    }
+
+    /// Product of the numbers of items inspected by the 2 most active monkeys.
+    pub fn monkey_business(&self) -> usize {
+        let mut inspections = self.inspections.clone();
+        inspections.sort_unstable_by(|a, b| b.cmp(a));
+        inspections.iter().take(2).product()
+    }
+}
+
+impl Day11Solver {
+    fn solve(input: &str, rounds: usize, modular: bool) -> Result<Answer> {
+        let mut troop = Troop::new(parse_monkeys(input)?);
+        profile::mark_parsed();
+
+        let overflow = || Error::NoSolution("worry levels do not fit in 64 bits".to_owned());
+        let relief = match modular {
+            true => troop.modular_relief().ok_or_else(overflow)?,
+            false => Relief::DivideByThree,
+        };
+        for _ in 0..rounds {
+            troop.play_round(relief).ok_or_else(overflow)?;
+        }
+
+        Ok(troop.monkey_business().into())
+    }
+
+    fn render(monkeys: &[Monkey]) -> String {
+        let blocks: Vec<_> = monkeys
+            .iter()
+            .enumerate()
+            .map(|(i, monkey)| {
+                let items: Vec<_> = monkey.items.iter().map(u64::to_string).collect();
+                format!(
+                    "Monkey {i}:\
+                     \n  Starting items: {}\
+                     \n  Operation: {}\
+                     \n  Test: divisible by {}\
+                     \n    If true: throw to monkey {}\
+                     \n    If false: throw to monkey {}",
+                    items.join(", "),
+                    monkey.operation.render(),
+                    monkey.divisor,
+                    monkey.if_divisible,
+                    monkey.if_not_divisible
+                )
+            })
+            .collect();
+        blocks.join("\n\n")
+    }
+}
+
+impl Solver for Day11Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    // `size` is the number of monkeys, between 3 and 9 so that the product of
+    // their divisors stays small. One of them squares worry levels, which may
+    // overflow without modular relief: such troops are generated again. With
+    // only 2 monkeys, every item would be squared every round.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let num_monkeys = size.clamp(3, GENERATED_DIVISORS.len());
+        loop {
+            let mut divisors = GENERATED_DIVISORS[..num_monkeys].to_vec();
+            divisors.shuffle(rng);
+            let squaring_monkey = rng.gen_range(0..num_monkeys);
+            let other_monkey = |rng: &mut StdRng, i| {
+                let target = rng.gen_range(0..num_monkeys - 1);
+                target + usize::from(target >= i)
+            };
+
+            let monkeys: Vec<_> = (0..num_monkeys)
+                .map(|i| {
+                    let operation = match (i == squaring_monkey, rng.gen_bool(0.5)) {
+                        (true, _) => Operation::Multiply(Operand::Old),
+                        (false, true) => Operation::Add(Operand::Value(rng.gen_range(1..=8))),
+                        (false, false) => {
+                            Operation::Multiply(Operand::Value(rng.gen_range(2..=19)))
+                        }
+                    };
+                    let if_divisible = other_monkey(rng, i);
+                    let mut if_not_divisible = other_monkey(rng, i);
+                    while if_not_divisible == if_divisible {
+                        if_not_divisible = other_monkey(rng, i);
+                    }
+                    Monkey {
+                        items: (0..rng.gen_range(1..=8))
+                            .map(|_| rng.gen_range(50..=99))
+                            .collect(),
+                        operation,
+                        divisor: divisors[i],
+                        if_divisible,
+                        if_not_divisible,
+                    }
+                })
+                .collect();
+
+            let mut troop = Troop::new(monkeys.clone());
+            if (0..20).all(|_| troop.play_round(Relief::DivideByThree).is_some()) {
+                return Some(Self::render(&monkeys));
+            }
+        }
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve(input, 20, false)
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve(input, 10_000, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day11Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(10605)
+        );
+        assert_eq!(
+            Day11Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(2713310158)
+        );
+    }
+
+    #[test]
+    fn rejects_monkeys_throwing_to_themselves() {
+        let input = EXAMPLE.replacen(
+            "If true: throw to monkey 2",
+            "If true: throw to monkey 0",
+            1,
+        );
+        let error = parse_monkeys(&input).unwrap_err();
+        assert_eq!((error.line, error.column), (5, 30));
+    }
+
+    // Squares overflow within a few rounds, and so does the product of divisors.
+    #[test]
+    fn reports_overflowing_worry_levels() {
+        let input = "Monkey 0:
+  Starting items: 4000000000
+  Operation: new = old * old
+  Test: divisible by 5000000011
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 5000000011
+    If true: throw to monkey 0
+    If false: throw to monkey 0";
+        for result in [
+            Day11Solver::solve_part1(input),
+            Day11Solver::solve_part2(input),
+        ] {
+            assert!(matches!(result, Err(Error::NoSolution(_))));
+        }
+    }
+}
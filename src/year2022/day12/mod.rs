@@ -0,0 +1,311 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseResult, Result};
+use crate::parse;
+use crate::profile;
+use crate::solver::{Part, Solver};
+
+/// Row and column of a square of the heightmap.
+pub type Position = (usize, usize);
+
+pub struct Day12Solver {}
+
+pub struct Heightmap {
+    // Elevations from 0 for `a` to 25 for `z`, row by row.
+    pub heights: Vec<Vec<u8>>,
+    // Marked `S`, at elevation `a`.
+    pub start: Position,
+    // Marked `E`, at elevation `z`.
+    pub end: Position,
+}
+
+/// Which way the heightmap is explored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    // Climbing at most one elevation up per step.
+    Ascending,
+    // Walking the climbs backwards, i.e. going at most one elevation down per step.
+    Descending,
+}
+
+impl Heightmap {
+    /// Parses a rectangular grid of elevations, with exactly one start and one end.
+    pub fn parse(input: &str) -> ParseResult<Self> {
+        let mut heights: Vec<Vec<u8>> = Vec::new();
+        let (mut start, mut end) = (None, None);
+
+        for (row, line) in parse::lines(input).enumerate() {
+            let squares = line
+                .chars()
+                .enumerate()
+                .map(|(column, (character, token))| {
+                    let marker = match character {
+                        'S' => Some((&mut start, 'a')),
+                        'E' => Some((&mut end, 'z')),
+                        'a'..='z' => None,
+                        _ => {
+                            return Err(token.error("an elevation between `a` and `z`, `S` or `E`"))
+                        }
+                    };
+                    let elevation = match marker {
+                        Some((position, elevation)) => {
+                            if position.is_some() {
+                                return Err(token.error(format!("a single `{character}`")));
+                            }
+                            *position = Some((row, column));
+                            elevation
+                        }
+                        None => character,
+                    };
+                    Ok(elevation as u8 - b'a')
+                })
+                .collect::<ParseResult<Vec<_>>>()?;
+            if let Some(width) = heights.first().map(Vec::len) {
+                if squares.len() != width {
+                    return Err(line.error(format!("a row of {width} squares")));
+                }
+            }
+
+            heights.push(squares);
+        }
+
+        Ok(Self {
+            heights,
+            start: start.ok_or_else(|| parse::end_of_input(input, "a start `S`"))?,
+            end: end.ok_or_else(|| parse::end_of_input(input, "an end `E`"))?,
+        })
+    }
+
+    fn height(&self, (row, column): Position) -> u8 {
+        self.heights[row][column]
+    }
+
+    fn can_step(&self, from: Position, to: Position, direction: Direction) -> bool {
+        match direction {
+            Direction::Ascending => self.height(to) <= self.height(from) + 1,
+            Direction::Descending => self.height(from) <= self.height(to) + 1,
+        }
+    }
+
+    // The squares next to `position` which can be stepped on from it.
+    fn neighbours(
+        &self,
+        (row, column): Position,
+        direction: Direction,
+    ) -> impl Iterator<Item = Position> + '_ {
+        let candidates = [
+            row.checked_sub(1).map(|row| (row, column)),
+            (row + 1 < self.heights.len()).then_some((row + 1, column)),
+            column.checked_sub(1).map(|column| (row, column)),
+            (column + 1 < self.heights[row].len()).then_some((row, column + 1)),
+        ];
+        candidates
+            .into_iter()
+            .flatten()
+            .filter(move |&neighbour| self.can_step((row, column), neighbour, direction))
+    }
+
+    /// Number of steps from `from` to every square, found by a breadth-first
+    /// search. Unreachable squares have no distance.
+    pub fn distances(&self, from: Position, direction: Direction) -> Vec<Vec<Option<usize>>> {
+        let mut distances: Vec<Vec<_>> = self
+            .heights
+            .iter()
+            .map(|row| vec![None; row.len()])
+            .collect();
+        distances[from.0][from.1] = Some(0);
+        let mut queue = VecDeque::from([from]);
+
+        while let Some(position) = queue.pop_front() {
+            let distance = distances[position.0][position.1].unwrap();
+            for (row, column) in self.neighbours(position, direction) {
+                if distances[row][column].is_none() {
+                    distances[row][column] = Some(distance + 1);
+                    queue.push_back((row, column));
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// The square at elevation `a` closest to the end, along with its number of
+    /// steps to the end, if any can reach it.
+    pub fn closest_lowest_square(&self) -> Option<(Position, usize)> {
+        // A single search from the end finds the closest of all the lowest squares.
+        let distances = self.distances(self.end, Direction::Descending);
+        (0..self.heights.len())
+            .flat_map(|row| (0..self.heights[row].len()).map(move |column| (row, column)))
+            .filter(|&position| self.height(position) == 0)
+            .filter_map(|(row, column)| Some(((row, column), distances[row][column]?)))
+            .min_by_key(|&(_, distance)| distance)
+    }
+
+    /// One of the shortest paths from `from` to the end, including both, if there is one.
+    pub fn shortest_path(&self, from: Position) -> Option<Vec<Position>> {
+        let distances = self.distances(self.end, Direction::Descending);
+        let mut path = vec![from];
+        let mut position = from;
+        let mut distance = distances[from.0][from.1]?;
+        // Every step brings the path one square closer to the end.
+        while distance > 0 {
+            position = self
+                .neighbours(position, Direction::Ascending)
+                .find(|&(row, column)| distances[row][column] == Some(distance - 1))
+                .unwrap();
+            path.push(position);
+            distance -= 1;
+        }
+
+        Some(path)
+    }
+
+    /// Draws the path over the heightmap like the puzzle does: every square of the
+    /// path shows the direction of the next step, the end is marked `E`, and every
+    /// other square is a `.`.
+    pub fn render_path(&self, path: &[Position]) -> Vec<String> {
+        let mut grid: Vec<Vec<char>> = self
+            .heights
+            .iter()
+            .map(|row| vec!['.'; row.len()])
+            .collect();
+        for step in path.windows(2) {
+            let ((row, column), (next_row, next_column)) = (step[0], step[1]);
+            grid[row][column] = match (next_row.cmp(&row), next_column.cmp(&column)) {
+                (Ordering::Less, _) => '^',
+                (Ordering::Greater, _) => 'v',
+                (_, Ordering::Less) => '<',
+                _ => '>',
+            };
+        }
+        if let Some(&(row, column)) = path.last() {
+            grid[row][column] = 'E';
+        }
+
+        grid.into_iter().map(String::from_iter).collect()
+    }
+}
+
+impl Solver for Day12Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    // `size` is roughly the number of squares. The grid is at least 26 squares
+    // wide, so that the elevation can rise from `a` on its left to `z` on its
+    // right. Grids where the end cannot be reached are generated again.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let height = ((size as f64 / 4.0).sqrt() as usize).max(5);
+        let width = (size / height).max(26);
+        loop {
+            let mut heights: Vec<Vec<u8>> = (0..height)
+                .map(|_| {
+                    (0..width)
+                        .map(|column| {
+                            let elevation = (column * 25 / (width - 1)) as u8;
+                            elevation.saturating_sub(rng.gen_range(0..=2))
+                        })
+                        .collect()
+                })
+                .collect();
+            let start = (rng.gen_range(0..height), 0);
+            let end = (rng.gen_range(0..height), width - 1);
+            heights[start.0][start.1] = 0;
+            heights[end.0][end.1] = 25;
+
+            let heightmap = Heightmap {
+                heights,
+                start,
+                end,
+            };
+            if heightmap.shortest_path(start).is_none() {
+                continue;
+            }
+
+            let rows: Vec<String> = (0..height)
+                .map(|row| {
+                    (0..width)
+                        .map(|column| match (row, column) {
+                            position if position == start => 'S',
+                            position if position == end => 'E',
+                            position => char::from(b'a' + heightmap.height(position)),
+                        })
+                        .collect()
+                })
+                .collect();
+            return Some(rows.join("\n"));
+        }
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let heightmap = Heightmap::parse(input)?;
+        profile::mark_parsed();
+
+        let (row, column) = heightmap.end;
+        heightmap.distances(heightmap.start, Direction::Ascending)[row][column]
+            .map(Answer::from)
+            .ok_or_else(|| Error::NoSolution("the end cannot be reached from the start".to_owned()))
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let heightmap = Heightmap::parse(input)?;
+        profile::mark_parsed();
+
+        heightmap
+            .closest_lowest_square()
+            .map(|(_, distance)| distance.into())
+            .ok_or_else(|| {
+                Error::NoSolution("the end cannot be reached from any square at `a`".to_owned())
+            })
+    }
+
+    // Draws the shortest path from the start, or from the closest lowest square.
+    fn visualize(input: &str, part: Part) -> Option<Result<Answer>> {
+        let draw = || {
+            let heightmap = Heightmap::parse(input)?;
+            let from = match part {
+                Part::Part1 => Some(heightmap.start),
+                Part::Part2 => heightmap.closest_lowest_square().map(|(from, _)| from),
+            };
+            from.and_then(|from| heightmap.shortest_path(from))
+                .map(|path| Answer::Grid(heightmap.render_path(&path)))
+                .ok_or_else(|| Error::NoSolution("the end cannot be reached".to_owned()))
+        };
+        Some(draw())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day12Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(31)
+        );
+        assert_eq!(
+            Day12Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(29)
+        );
+    }
+
+    #[test]
+    fn renders_the_shortest_path() {
+        let heightmap = Heightmap::parse(EXAMPLE).unwrap();
+        let path = heightmap.shortest_path(heightmap.start).unwrap();
+        assert_eq!(path.len(), 32);
+        // Not quite the path drawn by the puzzle, which is as short.
+        assert_eq!(
+            heightmap.render_path(&path),
+            ["v..v<<<<", ">v.vv<<^", ".v.v>E^^", ".>v>>>^^", "..>>>>>^"]
+        );
+        assert_eq!(heightmap.closest_lowest_square(), Some(((4, 0), 29)));
+    }
+}
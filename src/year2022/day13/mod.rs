@@ -0,0 +1,266 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::profile;
+use crate::solver::Solver;
+
+/// How deep lists may be nested in a packet. Parsing, comparing and dropping
+/// packets recurse into nested lists, which must not overflow the stack.
+pub const MAX_NESTING: usize = 100;
+
+pub struct Day13Solver {}
+
+#[derive(Debug, Clone)]
+pub enum Packet {
+    Integer(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    /// Parses a whole line, like `[1,[2,[]],3]`.
+    pub fn parse(line: Token) -> ParseResult<Self> {
+        let (packet, rest) = Self::parse_prefix(line, 0)?;
+        if !rest.text.is_empty() {
+            return Err(rest.error("the end of the packet"));
+        }
+        Ok(packet)
+    }
+
+    // Parses the packet at the start of `token`, and returns it along with the rest of `token`.
+    // `depth` is the number of lists the packet is in.
+    fn parse_prefix(token: Token, depth: usize) -> ParseResult<(Self, Token)> {
+        if let Some(mut rest) = token.strip_prefix("[") {
+            if depth == MAX_NESTING {
+                return Err(next_character(token).error(format!(
+                    "an integer, as lists are nested at most {MAX_NESTING} deep"
+                )));
+            }
+            let mut items = Vec::new();
+            if let Some(rest) = rest.strip_prefix("]") {
+                return Ok((Self::List(items), rest));
+            }
+            loop {
+                let (item, after_item) = Self::parse_prefix(rest, depth + 1)?;
+                items.push(item);
+                if let Some(after_comma) = after_item.strip_prefix(",") {
+                    rest = after_comma;
+                } else if let Some(after_list) = after_item.strip_prefix("]") {
+                    return Ok((Self::List(items), after_list));
+                } else {
+                    return Err(next_character(after_item).error("`,` or `]`"));
+                }
+            }
+        }
+
+        let digits = token.text.len()
+            - token
+                .text
+                .trim_start_matches(|character: char| character.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return Err(next_character(token).error("an integer or a list"));
+        }
+        let integer = token.slice(0..digits).parse("an integer")?;
+        Ok((
+            Self::Integer(integer),
+            token.slice(digits..token.text.len()),
+        ))
+    }
+
+    /// The packets added to the list of all packets to compute the decoder key.
+    pub fn dividers() -> [Self; 2] {
+        [2, 6].map(|divider| Self::List(vec![Self::List(vec![Self::Integer(divider)])]))
+    }
+}
+
+// Integers compare with lists as if they were a list of a single integer.
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Integer(left), Self::Integer(right)) => left.cmp(right),
+            // Lists compare item by item, then by length.
+            (Self::List(left), Self::List(right)) => left.cmp(right),
+            (Self::Integer(left), Self::List(right)) => {
+                [Self::Integer(*left)].as_slice().cmp(right.as_slice())
+            }
+            (Self::List(left), Self::Integer(right)) => {
+                left.as_slice().cmp([Self::Integer(*right)].as_slice())
+            }
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Consistent with `Ord`, so that `2` and `[2]` are equal.
+impl PartialEq for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Packet {}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Integer(integer) => write!(f, "{integer}"),
+            Self::List(items) => {
+                let items: Vec<_> = items.iter().map(Self::to_string).collect();
+                write!(f, "[{}]", items.join(","))
+            }
+        }
+    }
+}
+
+// The first character of `token`, or nothing at the end of the line.
+fn next_character(token: Token) -> Token {
+    let length = token.text.chars().next().map_or(0, char::len_utf8);
+    token.slice(0..length)
+}
+
+/// Parses the pairs of packets, which are separated by empty lines.
+pub fn parse_pairs(input: &str) -> ParseResult<Vec<(Packet, Packet)>> {
+    let mut lines = parse::lines(input);
+    let mut pairs = Vec::new();
+
+    while let Some(left) = lines.next() {
+        let right = lines
+            .next()
+            .ok_or_else(|| lines.end_of_input("the 2nd packet of the pair"))?;
+        pairs.push((Packet::parse(left)?, Packet::parse(right)?));
+
+        if let Some(separator) = lines.next() {
+            if !separator.text.is_empty() {
+                return Err(separator.error("an empty line between pairs"));
+            }
+        }
+    }
+
+    Ok(pairs)
+}
+
+impl Day13Solver {
+    // `depth` is how many more lists may be nested.
+    fn generate_packet(rng: &mut StdRng, depth: usize) -> Packet {
+        match depth > 0 && rng.gen_bool(0.6) {
+            true => Packet::List(
+                (0..rng.gen_range(0..=5))
+                    .map(|_| Self::generate_packet(rng, depth - 1))
+                    .collect(),
+            ),
+            false => Packet::Integer(rng.gen_range(0..=10)),
+        }
+    }
+}
+
+impl Solver for Day13Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    // `size` is the number of pairs of packets. Like in real inputs, no packet
+    // is equal to a divider, which would make the decoder key ambiguous.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let dividers = Packet::dividers();
+        let pairs: Vec<_> = (0..size)
+            .map(|_| {
+                // Every packet is a list, the outermost one.
+                let [left, right] = [(); 2].map(|_| loop {
+                    let items = (0..rng.gen_range(0..=5))
+                        .map(|_| Self::generate_packet(rng, 4))
+                        .collect();
+                    let packet = Packet::List(items);
+                    if !dividers.contains(&packet) {
+                        break packet;
+                    }
+                });
+                format!("{left}\n{right}")
+            })
+            .collect();
+        Some(pairs.join("\n\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let pairs = parse_pairs(input)?;
+        profile::mark_parsed();
+
+        // Pairs are numbered from 1.
+        let sum_ordered_indices: usize = pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| left < right)
+            .map(|(i, _)| i + 1)
+            .sum();
+        Ok(sum_ordered_indices.into())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let mut packets: Vec<_> = parse_pairs(input)?
+            .into_iter()
+            .flat_map(|(left, right)| [left, right])
+            .collect();
+        profile::mark_parsed();
+
+        let dividers = Packet::dividers();
+        packets.extend(dividers.iter().cloned());
+        packets.sort();
+
+        // Packets are numbered from 1. Each divider is found by a binary search,
+        // which puts it before any packet equal to it.
+        let decoder_key: usize = dividers
+            .iter()
+            .map(|divider| packets.partition_point(|packet| packet < divider) + 1)
+            .product();
+        Ok(decoder_key.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day13Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(13)
+        );
+        assert_eq!(
+            Day13Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(140)
+        );
+    }
+
+    #[test]
+    fn parses_nested_lists() {
+        let input = "[1,[2,[3,[4,[5,6,7]]]],8,9]\n[[]]";
+        let pairs = parse_pairs(input).unwrap();
+        assert_eq!(pairs[0].0.to_string(), "[1,[2,[3,[4,[5,6,7]]]],8,9]");
+        assert_eq!(pairs[0].1.to_string(), "[[]]");
+
+        let error = parse_pairs("[1,[2]\n[]").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 7));
+        assert_eq!(error.found, "nothing");
+    }
+
+    #[test]
+    fn rejects_deeply_nested_lists() {
+        let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        assert!(parse_pairs(&format!("{}\n[]", nested(MAX_NESTING))).is_ok());
+
+        let error = parse_pairs(&format!("[]\n{}", nested(200_000))).unwrap_err();
+        assert_eq!((error.line, error.column), (2, MAX_NESTING + 1));
+        assert_eq!(error.found, "`[`");
+    }
+}
@@ -0,0 +1,353 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::profile;
+use crate::solver::{Part, Solver};
+
+/// Where the sand pours from, as `(x, y)` where `y` grows downwards.
+pub const SAND_SOURCE: (i64, usize) = (500, 0);
+/// Points are within `0..=MAX_X` and `0..=MAX_Y`, so that the cave fits in memory.
+pub const MAX_X: i64 = 1_000;
+pub const MAX_Y: usize = 1_000;
+
+pub struct Day14Solver {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl From<&Tile> for char {
+    fn from(value: &Tile) -> Self {
+        match *value {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        }
+    }
+}
+
+/// What lies below the lowest rock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bottom {
+    // Sand falling past the lowest rock flows into it forever.
+    Abyss,
+    // An infinite floor, 2 below the lowest rock.
+    Floor,
+}
+
+pub struct Cave {
+    // Rows from `y = 0` to the floor, excluded. Columns span the rocks and the
+    // source, and with a floor, as far as the sand can spread on it, i.e. as
+    // deep as the floor on both sides of the source. Beyond them is only air.
+    tiles: Vec<Vec<Tile>>,
+    // `x` of the leftmost column.
+    min_x: i64,
+    floor_y: usize,
+    bottom: Bottom,
+}
+
+// Parses a point like `498,4`, within the bounds of the cave.
+fn parse_point(point: Token) -> ParseResult<(i64, usize)> {
+    let (x_token, y_token) = point.split_once(',', "a point like `498,4`")?;
+    let x_expected = format!("an x coordinate between 0 and {MAX_X}");
+    let y_expected = format!("a y coordinate between 0 and {MAX_Y}");
+    let (x, y) = (
+        x_token.parse::<i64>(x_expected.as_str())?,
+        y_token.parse::<usize>(y_expected.as_str())?,
+    );
+    if !(0..=MAX_X).contains(&x) {
+        return Err(x_token.error(x_expected));
+    }
+    if y > MAX_Y {
+        return Err(y_token.error(y_expected));
+    }
+    Ok((x, y))
+}
+
+/// Parses the paths of rock, each a line of points joined by straight segments.
+pub fn parse_paths(input: &str) -> ParseResult<Vec<Vec<(i64, usize)>>> {
+    parse::lines(input)
+        .map(|line| {
+            let mut path: Vec<(i64, usize)> = Vec::new();
+            for point_token in line.split(" -> ") {
+                let point = parse_point(point_token)?;
+                if let Some(&previous) = path.last() {
+                    if previous.0 != point.0 && previous.1 != point.1 {
+                        return Err(
+                            point_token.error("a point in line with the previous one of the path")
+                        );
+                    }
+                }
+                path.push(point);
+            }
+            Ok(path)
+        })
+        .collect()
+}
+
+impl Cave {
+    /// Returns None if the cave is too large to be represented, which cannot
+    /// happen with parsed paths, see `MAX_X` and `MAX_Y`.
+    pub fn new(paths: &[Vec<(i64, usize)>], bottom: Bottom) -> Option<Self> {
+        let points = paths.iter().flatten();
+        let lowest_rock = points
+            .clone()
+            .map(|&(_, y)| y)
+            .max()
+            .unwrap_or(SAND_SOURCE.1);
+        let floor_y = lowest_rock.checked_add(2)?;
+        // Sand falling past the rocks flows into the abyss, so only a floor widens the cave.
+        let spread = match bottom {
+            Bottom::Abyss => 0,
+            Bottom::Floor => i64::try_from(floor_y).ok()?,
+        };
+        let min_x = points
+            .clone()
+            .map(|&(x, _)| x)
+            .min()
+            .unwrap_or(SAND_SOURCE.0)
+            .min(SAND_SOURCE.0.checked_sub(spread)?);
+        let max_x = points
+            .map(|&(x, _)| x)
+            .max()
+            .unwrap_or(SAND_SOURCE.0)
+            .max(SAND_SOURCE.0.checked_add(spread)?);
+        let width = usize::try_from(max_x.checked_sub(min_x)?.checked_add(1)?).ok()?;
+
+        let mut cave = Self {
+            tiles: vec![vec![Tile::Air; width]; floor_y],
+            min_x,
+            floor_y,
+            bottom,
+        };
+        for path in paths {
+            // A path of a single point is a single rock.
+            let segments = path
+                .windows(2)
+                .map(|segment| (segment[0], segment[1]))
+                .chain(path.first().map(|&point| (point, point)));
+            for (from, to) in segments {
+                for x in from.0.min(to.0)..=from.0.max(to.0) {
+                    for y in from.1.min(to.1)..=from.1.max(to.1) {
+                        *cave.tile_mut((x, y)) = Tile::Rock;
+                    }
+                }
+            }
+        }
+
+        Some(cave)
+    }
+
+    // Index of the column of `x`, if it is within the cave's columns.
+    fn column(&self, x: i64) -> Option<usize> {
+        let column = usize::try_from(x.checked_sub(self.min_x)?).ok()?;
+        (column < self.tiles[0].len()).then_some(column)
+    }
+
+    fn tile_mut(&mut self, (x, y): (i64, usize)) -> &mut Tile {
+        let column = self.column(x).expect("tiles at rest are within the cave");
+        &mut self.tiles[y][column]
+    }
+
+    fn tile(&self, (x, y): (i64, usize)) -> Tile {
+        self.column(x)
+            .map_or(Tile::Air, |column| self.tiles[y][column])
+    }
+
+    /// Drops a unit of sand from the source, and returns where it comes to rest.
+    /// Returns None if it flows into the abyss, or if the source is blocked.
+    pub fn drop_sand(&mut self) -> Option<(i64, usize)> {
+        if self.tile(SAND_SOURCE) != Tile::Air {
+            return None;
+        }
+
+        let (mut x, mut y) = SAND_SOURCE;
+        loop {
+            if y + 1 == self.floor_y {
+                match self.bottom {
+                    Bottom::Abyss => return None,
+                    Bottom::Floor => break,
+                }
+            }
+            // Straight down first, then diagonally to the left, then to the right.
+            match [x, x - 1, x + 1]
+                .into_iter()
+                .find(|&next_x| self.tile((next_x, y + 1)) == Tile::Air)
+            {
+                Some(next_x) => (x, y) = (next_x, y + 1),
+                None => break,
+            }
+        }
+
+        *self.tile_mut((x, y)) = Tile::Sand;
+        Some((x, y))
+    }
+
+    /// Drops sand until it stops coming to rest, and returns how many units did.
+    pub fn fill(&mut self) -> usize {
+        let mut units = 0;
+        while self.drop_sand().is_some() {
+            units += 1;
+        }
+        units
+    }
+
+    /// Draws the cave like the puzzle does, cropped around the source, rocks and
+    /// sand. The source is marked `+`, and the floor is drawn if there is one.
+    pub fn render(&self) -> Vec<String> {
+        let is_drawn = |tile: &Tile| *tile != Tile::Air;
+        let columns = self.tiles.iter().filter_map(|row| {
+            let first = row.iter().position(is_drawn)?;
+            Some((first, row.iter().rposition(is_drawn)?))
+        });
+        let source_column = self.column(SAND_SOURCE.0).unwrap();
+        let (first_column, last_column) = columns.fold(
+            (source_column, source_column),
+            |(first, last), (row_first, row_last)| (first.min(row_first), last.max(row_last)),
+        );
+        let last_row = self
+            .tiles
+            .iter()
+            .rposition(|row| row.iter().any(is_drawn))
+            .unwrap_or(SAND_SOURCE.1);
+
+        let mut rows: Vec<String> = self.tiles[..=last_row]
+            .iter()
+            .map(|row| {
+                row[first_column..=last_column]
+                    .iter()
+                    .map(char::from)
+                    .collect()
+            })
+            .collect();
+        rows[SAND_SOURCE.1].replace_range(
+            source_column - first_column..=source_column - first_column,
+            "+",
+        );
+        if self.bottom == Bottom::Floor {
+            // Empty rows above the floor are left out.
+            rows.push("#".repeat(last_column - first_column + 1));
+        }
+        rows
+    }
+}
+
+impl Day14Solver {
+    fn cave(input: &str, bottom: Bottom) -> Result<Cave> {
+        Cave::new(&parse_paths(input)?, bottom)
+            .ok_or_else(|| Error::NoSolution("the cave is too large".to_owned()))
+    }
+
+    fn solve(input: &str, bottom: Bottom) -> Result<Answer> {
+        let mut cave = Self::cave(input, bottom)?;
+        profile::mark_parsed();
+
+        Ok(cave.fill().into())
+    }
+}
+
+impl Solver for Day14Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    // `size` is the number of paths of rock, each of a few segments below the source.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let paths: Vec<_> = (0..size)
+            .map(|_| {
+                let (mut x, mut y) = (rng.gen_range(460..=540), rng.gen_range(20..=170));
+                let mut points = vec![format!("{x},{y}")];
+                for i in 0..rng.gen_range(1..=5) {
+                    // Segments alternate between horizontal and vertical ones.
+                    let length = rng.gen_range(1..=6);
+                    match (i % 2 == 0, rng.gen_bool(0.5)) {
+                        (true, true) => x += length,
+                        (true, false) => x -= length,
+                        (false, true) => y += length,
+                        (false, false) => y -= length,
+                    }
+                    points.push(format!("{x},{y}"));
+                }
+                points.join(" -> ")
+            })
+            .collect();
+        Some(paths.join("\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve(input, Bottom::Abyss)
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve(input, Bottom::Floor)
+    }
+
+    // Draws the cave once sand stops coming to rest.
+    fn visualize(input: &str, part: Part) -> Option<Result<Answer>> {
+        let bottom = match part {
+            Part::Part1 => Bottom::Abyss,
+            Part::Part2 => Bottom::Floor,
+        };
+        Some(Self::cave(input, bottom).map(|mut cave| {
+            cave.fill();
+            Answer::Grid(cave.render())
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day14Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(24)
+        );
+        assert_eq!(
+            Day14Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(93)
+        );
+    }
+
+    #[test]
+    fn renders_the_cave() {
+        let mut cave = Cave::new(&parse_paths(EXAMPLE).unwrap(), Bottom::Abyss).unwrap();
+        cave.fill();
+        assert_eq!(
+            cave.render(),
+            [
+                "......+...",
+                "..........",
+                "......o...",
+                ".....ooo..",
+                "....#ooo##",
+                "...o#ooo#.",
+                "..###ooo#.",
+                "....oooo#.",
+                ".o.ooooo#.",
+                "#########.",
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_points_out_of_the_cave() {
+        for (input, column) in [
+            ("500,100000000000", 5),
+            ("0,18446744073709551614", 3),
+            ("498,4 -> -9223372036854775808,4", 10),
+        ] {
+            let error = parse_paths(input).unwrap_err();
+            assert_eq!((error.line, error.column), (1, column), "{input}");
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::ops::Bound;
+use unbounded_interval_tree::interval_tree::IntervalTree;
+
+use crate::answer::Answer;
+use crate::error::{Error, ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::profile;
+use crate::solver::Solver;
+
+lazy_static! {
+    static ref SENSOR_RE: Regex =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+}
+
+/// Row whose excluded positions are counted in part 1.
+pub const TARGET_ROW: i64 = 2_000_000;
+/// The distress beacon of part 2 is within `0..=SEARCH_LIMIT` on both axes.
+pub const SEARCH_LIMIT: i64 = 4_000_000;
+/// Coordinates are within `-MAX_COORDINATE..=MAX_COORDINATE`, so that distances
+/// and boundaries between positions cannot overflow.
+pub const MAX_COORDINATE: i64 = 1_000_000_000;
+
+pub type Position = (i64, i64);
+
+pub struct Day15Solver {}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Sensor {
+    pub position: Position,
+    // The closest beacon, any other one is further away.
+    pub beacon: Position,
+}
+
+fn distance((x1, y1): Position, (x2, y2): Position) -> i64 {
+    (x1 - x2).abs() + (y1 - y2).abs()
+}
+
+impl Sensor {
+    pub fn parse(line: Token) -> ParseResult<Self> {
+        let captures = SENSOR_RE.captures(line.text).ok_or_else(|| {
+            line.error("a line like `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`")
+        })?;
+        let expected = format!("a coordinate between -{MAX_COORDINATE} and {MAX_COORDINATE}");
+        let coordinate = |index| {
+            let token = line.slice(captures.get(index).unwrap().range());
+            match token.parse::<i64>(expected.as_str())? {
+                coordinate if (-MAX_COORDINATE..=MAX_COORDINATE).contains(&coordinate) => {
+                    Ok(coordinate)
+                }
+                _ => Err(token.error(expected.as_str())),
+            }
+        };
+        Ok(Self {
+            position: (coordinate(1)?, coordinate(2)?),
+            beacon: (coordinate(3)?, coordinate(4)?),
+        })
+    }
+
+    /// No beacon can be closer to the sensor than this.
+    pub fn radius(&self) -> i64 {
+        distance(self.position, self.beacon)
+    }
+
+    // The positions of row `y` which are within the sensor's radius.
+    fn row_coverage(&self, y: i64) -> Option<(i64, i64)> {
+        let (x, sensor_y) = self.position;
+        let half_width = self.radius() - (sensor_y - y).abs();
+        (half_width >= 0).then_some((x - half_width, x + half_width))
+    }
+
+    // Lines just out of the sensor's reach: two going up and right, as constants
+    // `y - x`, and two going down and right, as constants `y + x`.
+    fn boundaries(&self) -> ([i64; 2], [i64; 2]) {
+        let (x, y) = self.position;
+        let reach = self.radius() + 1;
+        (
+            [y - x - reach, y - x + reach],
+            [y + x - reach, y + x + reach],
+        )
+    }
+}
+
+pub fn parse_sensors(input: &str) -> ParseResult<Vec<Sensor>> {
+    parse::lines(input).map(Sensor::parse).collect()
+}
+
+/// Positions of row `y` within the radius of any sensor, as inclusive ranges.
+pub fn row_coverage(sensors: &[Sensor], y: i64) -> IntervalTree<i64> {
+    let mut coverage = IntervalTree::default();
+    for (start, end) in sensors.iter().filter_map(|sensor| sensor.row_coverage(y)) {
+        coverage.insert(start..=end);
+    }
+    coverage
+}
+
+// Bounds of a gap of an interval tree, as an inclusive range.
+fn inclusive_bounds((start, end): (Bound<&i64>, Bound<&i64>)) -> (i64, i64) {
+    let start = match start {
+        Bound::Included(start) => *start,
+        Bound::Excluded(start) => start + 1,
+        Bound::Unbounded => i64::MIN,
+    };
+    let end = match end {
+        Bound::Included(end) => *end,
+        Bound::Excluded(end) => end - 1,
+        Bound::Unbounded => i64::MAX,
+    };
+    (start, end)
+}
+
+/// Number of positions of row `y` where no beacon can be, i.e. within the radius
+/// of a sensor, without a known beacon.
+pub fn count_excluded_positions(sensors: &[Sensor], y: i64) -> usize {
+    let coverage = row_coverage(sensors, y);
+    let ranges = sensors.iter().filter_map(|sensor| sensor.row_coverage(y));
+    let (Some(start), Some(end)) = (
+        ranges.clone().map(|(start, _)| start).min(),
+        ranges.map(|(_, end)| end).max(),
+    ) else {
+        return 0;
+    };
+
+    let span = start..=end;
+    let uncovered: i64 = coverage
+        .get_interval_difference(&span)
+        .into_iter()
+        .map(|gap| {
+            let (gap_start, gap_end) = inclusive_bounds(gap);
+            gap_end - gap_start + 1
+        })
+        .sum();
+    // Beacons are within the radius of their sensor, so they are all covered.
+    let beacons: BTreeSet<_> = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.1 == y)
+        .map(|sensor| sensor.beacon)
+        .collect();
+
+    (end - start + 1 - uncovered) as usize - beacons.len()
+}
+
+/// The first position within `0..=limit` on both axes which no sensor covers.
+///
+/// Scanning every row would take too long, so only the rows where the distress
+/// beacon can be are checked: a position surrounded by covered ones lies just
+/// out of the reach of several sensors, at the crossing of their boundaries,
+/// or of a boundary and an edge of the search area.
+pub fn find_distress_beacon(sensors: &[Sensor], limit: i64) -> Option<Position> {
+    let (ascending, descending): (Vec<_>, Vec<_>) = sensors.iter().map(Sensor::boundaries).unzip();
+    let ascending: BTreeSet<_> = ascending.into_iter().flatten().collect();
+    let descending: BTreeSet<_> = descending.into_iter().flatten().collect();
+
+    let mut rows = BTreeSet::from([0, limit]);
+    for &up in &ascending {
+        // Crossings with the left and right edges.
+        rows.extend([up, up + limit]);
+        for &down in &descending {
+            if (up + down) % 2 == 0 {
+                rows.insert((up + down) / 2);
+            }
+        }
+    }
+    for &down in &descending {
+        rows.extend([down, down - limit]);
+    }
+
+    let area = 0..=limit;
+    rows.into_iter().filter(|y| area.contains(y)).find_map(|y| {
+        let coverage = row_coverage(sensors, y);
+        let gap = coverage.get_interval_difference(&area).into_iter().next()?;
+        Some((inclusive_bounds(gap).0, y))
+    })
+}
+
+pub fn tuning_frequency((x, y): Position) -> i64 {
+    x * 4_000_000 + y
+}
+
+impl Solver for Day15Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    // `size` is the number of sensors, up to 40 as part 2 checks every crossing of
+    // their boundaries. A distress beacon is picked first, and every sensor
+    // reaches right up to it. Sensors beyond the 4 corners of the search area then
+    // leave no other position uncovered.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let distress_beacon = (
+            rng.gen_range(0..=SEARCH_LIMIT),
+            rng.gen_range(0..=SEARCH_LIMIT),
+        );
+        let margin = SEARCH_LIMIT / 20;
+        let corners = [(-1, -1), (-1, 1), (1, -1), (1, 1)].map(|(x_side, y_side)| {
+            let coordinate = |rng: &mut StdRng, side| match side {
+                -1 => rng.gen_range(-margin..=0),
+                _ => rng.gen_range(SEARCH_LIMIT..=SEARCH_LIMIT + margin),
+            };
+            (coordinate(rng, x_side), coordinate(rng, y_side))
+        });
+        let others: Vec<_> = (0..size.min(40))
+            .map(|_| {
+                (
+                    rng.gen_range(0..=SEARCH_LIMIT),
+                    rng.gen_range(0..=SEARCH_LIMIT),
+                )
+            })
+            .filter(|&position| distance(position, distress_beacon) >= 2)
+            .collect();
+
+        let mut lines: Vec<_> = corners
+            .into_iter()
+            .chain(others)
+            .map(|(x, y)| {
+                // The beacon is anywhere at the sensor's radius.
+                let radius = distance((x, y), distress_beacon) - 1;
+                let dx = rng.gen_range(-radius..=radius);
+                let dy = (radius - dx.abs()) * if rng.gen_bool(0.5) { 1 } else { -1 };
+                format!(
+                    "Sensor at x={x}, y={y}: closest beacon is at x={}, y={}",
+                    x + dx,
+                    y + dy
+                )
+            })
+            .collect();
+        lines.shuffle(rng);
+        Some(lines.join("\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let sensors = parse_sensors(input)?;
+        profile::mark_parsed();
+
+        Ok(count_excluded_positions(&sensors, TARGET_ROW).into())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let sensors = parse_sensors(input)?;
+        profile::mark_parsed();
+
+        find_distress_beacon(&sensors, SEARCH_LIMIT)
+            .map(|position| tuning_frequency(position).into())
+            .ok_or_else(|| {
+                Error::NoSolution("every position of the search area is covered".to_owned())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    // The example is searched with smaller bounds than the real input.
+    #[test]
+    fn solves_example() {
+        let sensors = parse_sensors(EXAMPLE).unwrap();
+        assert_eq!(count_excluded_positions(&sensors, 10), 26);
+        let distress_beacon = find_distress_beacon(&sensors, 20).unwrap();
+        assert_eq!(distress_beacon, (14, 11));
+        assert_eq!(tuning_frequency(distress_beacon), 56000011);
+    }
+
+    #[test]
+    fn rejects_coordinates_out_of_bounds() {
+        let line = "Sensor at x=0, y=0: closest beacon is at x=-9223372036854775808, y=0";
+        let error = parse_sensors(line).unwrap_err();
+        assert_eq!((error.line, error.column), (1, 44));
+        assert_eq!(error.found, "`-9223372036854775808`");
+    }
+}
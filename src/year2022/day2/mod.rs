@@ -0,0 +1,179 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::solver::Solver;
+
+#[derive(Copy, Clone)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+#[derive(Copy, Clone)]
+pub enum FightResult {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl FightResult {
+    /// Parses the expected result of a fight, as used in part 2.
+    pub fn parse(symbol: Token) -> ParseResult<Self> {
+        match symbol.text {
+            "X" => Ok(Self::Loss),
+            "Y" => Ok(Self::Draw),
+            "Z" => Ok(Self::Win),
+            _ => Err(symbol.error("one of `X`, `Y` or `Z`")),
+        }
+    }
+
+    pub fn points(&self) -> i32 {
+        match self {
+            Self::Win => 6,
+            Self::Draw => 3,
+            Self::Loss => 0,
+        }
+    }
+}
+
+impl Hand {
+    /// Parses the adversary's hand.
+    pub fn parse_adversary(symbol: Token) -> ParseResult<Self> {
+        match symbol.text {
+            "A" => Ok(Self::Rock),
+            "B" => Ok(Self::Paper),
+            "C" => Ok(Self::Scissors),
+            _ => Err(symbol.error("one of `A`, `B` or `C`")),
+        }
+    }
+
+    /// Parses our hand, as used in part 1.
+    pub fn parse_ours(symbol: Token) -> ParseResult<Self> {
+        match symbol.text {
+            "X" => Ok(Self::Rock),
+            "Y" => Ok(Self::Paper),
+            "Z" => Ok(Self::Scissors),
+            _ => Err(symbol.error("one of `X`, `Y` or `Z`")),
+        }
+    }
+
+    pub fn fight(&self, other_hand: Hand) -> FightResult {
+        match (self, other_hand) {
+            (Self::Rock, Self::Rock)
+            | (Self::Paper, Self::Paper)
+            | (Self::Scissors, Self::Scissors) => FightResult::Draw,
+            (Self::Rock, Self::Scissors)
+            | (Self::Paper, Self::Rock)
+            | (Self::Scissors, Self::Paper) => FightResult::Win,
+            _ => FightResult::Loss,
+        }
+    }
+
+    pub fn points(&self) -> i32 {
+        match self {
+            Self::Rock => 1,
+            Self::Paper => 2,
+            Self::Scissors => 3,
+        }
+    }
+
+    pub fn hand_to_play_if(&self, expected_result: FightResult) -> Self {
+        match (self, expected_result) {
+            (Self::Rock, FightResult::Draw)
+            | (Self::Paper, FightResult::Loss)
+            | (Self::Scissors, FightResult::Win) => Self::Rock,
+            (Self::Rock, FightResult::Win)
+            | (Self::Paper, FightResult::Draw)
+            | (Self::Scissors, FightResult::Loss) => Self::Paper,
+            _ => Self::Scissors,
+        }
+    }
+}
+
+pub struct Day2Solver {}
+impl Solver for Day2Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    // `size` is the number of rounds.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let rounds: Vec<_> = (0..size)
+            .map(|_| {
+                let adversary = char::from(b'A' + rng.gen_range(0..3));
+                let ours = char::from(b'X' + rng.gen_range(0..3));
+                format!("{adversary} {ours}")
+            })
+            .collect();
+        Some(rounds.join("\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut total_points = 0;
+
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            let (adversary_symbol, our_symbol) = line
+                .token()
+                .split_once(' ', "two space-separated symbols")?;
+            let adversary_hand = Hand::parse_adversary(adversary_symbol)?;
+            let our_hand = Hand::parse_ours(our_symbol)?;
+
+            let fight_result = our_hand.fight(adversary_hand);
+            total_points += fight_result.points() + our_hand.points();
+        }
+
+        Ok(total_points.into())
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut total_points = 0;
+
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            let (adversary_symbol, fight_symbol) = line
+                .token()
+                .split_once(' ', "two space-separated symbols")?;
+            let adversary_hand = Hand::parse_adversary(adversary_symbol)?;
+            let fight_result = FightResult::parse(fight_symbol)?;
+
+            let our_hand = adversary_hand.hand_to_play_if(fight_result);
+            total_points += fight_result.points() + our_hand.points();
+        }
+
+        Ok(total_points.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day2Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(15)
+        );
+        assert_eq!(
+            Day2Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(12)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::solver::Solver;
+
+pub fn build_priority_map() -> HashMap<char, u32> {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .enumerate()
+        .map(|(priority, item)| (item, (priority + 1) as u32))
+        .collect()
+}
+
+/// Checks that every item of the rupsack is a letter, i.e. that it has a priority.
+pub fn check_items(rupsack: Token) -> ParseResult<()> {
+    match rupsack
+        .chars()
+        .find(|(item, _)| !item.is_ascii_alphabetic())
+    {
+        Some((_, item)) => Err(item.error("an item between `a` and `z`, or `A` and `Z`")),
+        None => Ok(()),
+    }
+}
+
+pub struct Day3Solver {}
+impl Solver for Day3Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    // `size` is the number of rupsacks, rounded up to complete the last group.
+    // Every group shares a single badge, and the compartments of every rupsack
+    // share a single item. To do so, each rupsack of a group draws its other
+    // items from its own pool, split in one pool per compartment.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let mut items: Vec<_> = ('a'..='z').chain('A'..='Z').collect();
+        let mut rupsacks: Vec<String> = Vec::with_capacity(size + 2);
+        for _ in 0..size.div_ceil(3) {
+            items.shuffle(rng);
+            let (&badge, others) = items.split_last().unwrap();
+            for pool in others.chunks(others.len() / 3) {
+                let (first_pool, second_pool) = pool.split_at(pool.len() / 2);
+                let shared_item = if rng.gen_bool(0.2) {
+                    badge
+                } else {
+                    *first_pool.choose(rng).unwrap()
+                };
+
+                let compartment_size = rng.gen_range(2..=16);
+                let mut first_compartment = vec![shared_item, badge];
+                first_compartment.dedup();
+                while first_compartment.len() < compartment_size {
+                    first_compartment.push(*first_pool.choose(rng).unwrap());
+                }
+                let mut second_compartment = vec![shared_item];
+                while second_compartment.len() < compartment_size {
+                    second_compartment.push(*second_pool.choose(rng).unwrap());
+                }
+
+                first_compartment.shuffle(rng);
+                second_compartment.shuffle(rng);
+                rupsacks.push(
+                    first_compartment
+                        .into_iter()
+                        .chain(second_compartment)
+                        .collect(),
+                );
+            }
+        }
+        Some(rupsacks.join("\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let priority_map = build_priority_map();
+        let mut total_priority: u32 = 0;
+
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            let line = line.token();
+            check_items(line)?;
+            let line_len = line.text.len();
+            if line_len % 2 != 0 {
+                return Err(line.error("an even number of items").into());
+            }
+
+            let first_compartment: HashSet<char> = line.text[..line_len / 2].chars().collect();
+            let second_compartment = line.text[line_len / 2..].chars().collect::<HashSet<char>>();
+            let mistaken_item: &char = first_compartment
+                .intersection(&second_compartment)
+                .next()
+                .ok_or_else(|| line.error("an item in both compartments"))?;
+            total_priority += priority_map[mistaken_item];
+        }
+
+        Ok(total_priority.into())
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let priority_map = build_priority_map();
+        let mut total_priority: u32 = 0;
+        let mut lines = parse::read_lines(reader);
+
+        while let Some(first_rupsack) = lines.next() {
+            let first_rupsack = first_rupsack?;
+            let second_rupsack = lines
+                .next()
+                .ok_or_else(|| first_rupsack.end_of_input("a group of three rupsacks"))??;
+            let third_rupsack = lines
+                .next()
+                .ok_or_else(|| second_rupsack.end_of_input("a group of three rupsacks"))??;
+            let (first_rupsack, second_rupsack, third_rupsack_line) = (
+                first_rupsack.token(),
+                second_rupsack.token(),
+                third_rupsack.token(),
+            );
+            for rupsack in [first_rupsack, second_rupsack, third_rupsack_line] {
+                check_items(rupsack)?;
+            }
+
+            let first_rupsack: HashSet<char> = first_rupsack.text.chars().collect();
+            let second_rupsack: HashSet<char> = second_rupsack.text.chars().collect();
+            let third_rupsack: HashSet<char> = third_rupsack_line.text.chars().collect();
+            let first_and_second_rupsack_common: HashSet<char> = first_rupsack
+                .intersection(&second_rupsack)
+                .copied()
+                .collect();
+            let badge = first_and_second_rupsack_common
+                .intersection(&third_rupsack)
+                .next()
+                .ok_or_else(|| third_rupsack_line.error("an item common to the group"))?;
+
+            total_priority += priority_map[badge];
+        }
+
+        Ok(total_priority.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day3Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(157)
+        );
+        assert_eq!(
+            Day3Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(70)
+        );
+    }
+}
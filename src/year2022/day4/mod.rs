@@ -0,0 +1,114 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::io::BufRead;
+use unbounded_interval_tree::interval_tree::IntervalTree;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::solver::Solver;
+
+pub fn parse_range_str(range: Token) -> ParseResult<(i32, i32)> {
+    let (start, end) = range.split_once('-', "a range of sections, like `2-4`")?;
+    Ok((
+        start.parse("a section number")?,
+        end.parse("a section number")?,
+    ))
+}
+
+pub struct Day4Solver {}
+impl Solver for Day4Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    // `size` is the number of pairs of elves.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let mut range = || {
+            let start = rng.gen_range(1..=99);
+            let end = rng.gen_range(start..=99);
+            format!("{start}-{end}")
+        };
+        let pairs: Vec<_> = (0..size)
+            .map(|_| {
+                let first_range = range();
+                let second_range = range();
+                format!("{first_range},{second_range}")
+            })
+            .collect();
+        Some(pairs.join("\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut num_of_full_overlaps = 0;
+
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            let (fist_range_str, second_range_str) =
+                line.token().split_once(',', "two comma-separated ranges")?;
+            let (first_start, first_end) = parse_range_str(fist_range_str)?;
+            let (second_start, second_end) = parse_range_str(second_range_str)?;
+            let first_interval = first_start..=first_end;
+            let second_interval = second_start..=second_end;
+
+            if IntervalTree::from([first_interval.clone()]).contains_interval(&second_interval)
+                || IntervalTree::from([second_interval.clone()]).contains_interval(&first_interval)
+            {
+                num_of_full_overlaps += 1;
+            }
+        }
+
+        Ok(num_of_full_overlaps.into())
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut num_of_full_overlaps = 0;
+
+        for line in parse::read_lines(reader) {
+            let line = line?;
+            let (fist_range_str, second_range_str) =
+                line.token().split_once(',', "two comma-separated ranges")?;
+            let (first_start, first_end) = parse_range_str(fist_range_str)?;
+            let (second_start, second_end) = parse_range_str(second_range_str)?;
+            let first_interval = first_start..=first_end;
+            let second_interval = second_start..=second_end;
+
+            if !IntervalTree::from([first_interval])
+                .get_interval_overlaps(&second_interval)
+                .is_empty()
+            {
+                num_of_full_overlaps += 1;
+            }
+        }
+
+        Ok(num_of_full_overlaps.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day4Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(2)
+        );
+        assert_eq!(
+            Day4Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(4)
+        );
+    }
+}
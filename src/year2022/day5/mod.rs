@@ -0,0 +1,296 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::Regex;
+use std::iter::Peekable;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Lines, Token};
+use crate::solver::Solver;
+
+pub type Stack = Vec<char>;
+pub type Stacks = Vec<Stack>;
+
+lazy_static! {
+    static ref INSTRUCTIONS_RE: Regex = Regex::new(r"^move (\d+) from (\d) to (\d)$").unwrap();
+}
+
+// 3 chars per stack, and 1 whitespace in between stacks.
+const STACK_WIDTH: usize = 4;
+
+pub struct Day5Solver<'a> {
+    input: &'a str,
+    lines: Peekable<Lines<'a>>,
+    stacks: Stacks,
+}
+
+pub struct Instruction {
+    pub num_moves: usize,
+    pub stack_index_from: usize,
+    pub stack_index_to: usize,
+}
+
+impl<'a> Day5Solver<'a> {
+    pub fn new(input: &'a str) -> ParseResult<Self> {
+        let mut lines = parse::lines(input).peekable();
+        lines
+            .peek()
+            .ok_or_else(|| parse::end_of_input(input, "a drawing of the stacks"))?;
+        Ok(Self {
+            input,
+            lines,
+            stacks: Stacks::new(),
+        })
+    }
+
+    pub fn parse_initial_configuration(&mut self) -> ParseResult<()> {
+        let mut drawing = Vec::new();
+        let indices = loop {
+            match self.lines.next() {
+                Some(line) if line.text.starts_with(" 1") => break line,
+                Some(line) => drawing.push(line),
+                None => {
+                    return Err(parse::end_of_input(
+                        self.input,
+                        "a line with the indices of the stacks",
+                    ))
+                }
+            }
+        };
+        // Skip the empty line after the indices.
+        self.lines.next();
+
+        // Count the stacks from their indices, as trailing blanks may have been
+        // trimmed from every line of the drawing.
+        let num_of_stacks = indices.text.split_whitespace().count();
+        self.stacks = vec![Stack::default(); num_of_stacks];
+        // Subtract one for the last stack, which has no buffer.
+        let drawing_width = num_of_stacks * STACK_WIDTH - 1;
+
+        for line in drawing {
+            let extra_crate = line
+                .text
+                .char_indices()
+                .find(|&(i, c)| i >= drawing_width && !c.is_whitespace());
+            if let Some((start, _)) = extra_crate {
+                return Err(line.slice(start..line.text.len()).error(format!(
+                    "the end of the line, as there are {num_of_stacks} stacks"
+                )));
+            }
+
+            for (i, stack) in self.stacks.iter_mut().enumerate() {
+                let window_start = (i * STACK_WIDTH).min(line.text.len());
+                let window = line.slice(window_start..line.text.len());
+                match window.text {
+                    // Trailing blanks may have been trimmed from the line.
+                    text if text.chars().take(3).all(|c| c == ' ') => continue,
+                    text if text.starts_with('[') && text.len() >= 3 => {
+                        // The 2nd character in the window is the crate identifier.
+                        stack.push(text.chars().nth(1).unwrap())
+                    }
+                    _ => return Err(window.error("a crate like `[A]`, or a blank")),
+                }
+            }
+        }
+
+        // We built the stacks upside down, so we reverse them.
+        self.stacks.iter_mut().for_each(|stack| stack.reverse());
+        Ok(())
+    }
+
+    /// Parses an instruction, and checks that it can be applied to the current stacks.
+    pub fn parse_intruction(&self, instruction: Token) -> ParseResult<Instruction> {
+        let captures = INSTRUCTIONS_RE
+            .captures(instruction.text)
+            .ok_or_else(|| instruction.error("an instruction like `move 1 from 2 to 3`"))?;
+        let detail = |index| instruction.slice(captures.get(index).unwrap().range());
+        let stack_index = |index| {
+            let detail = detail(index);
+            match detail.parse::<usize>("a stack index")? {
+                // Decrement the parsed index, as the instruction is 1th-based
+                // whereas the stacks are 0th-based.
+                stack_index if (1..=self.stacks.len()).contains(&stack_index) => {
+                    Ok(stack_index - 1)
+                }
+                _ => {
+                    Err(detail.error(format!("a stack index between 1 and {}", self.stacks.len())))
+                }
+            }
+        };
+
+        let stack_index_from = stack_index(2)?;
+        let stack_index_to = stack_index(3)?;
+        let num_moves = detail(1).parse("a number of crates")?;
+        let num_crates = self.stacks[stack_index_from].len();
+        if num_moves > num_crates {
+            return Err(detail(1).error(format!("at most {num_crates} crates to move")));
+        }
+
+        Ok(Instruction {
+            num_moves,
+            stack_index_from,
+            stack_index_to,
+        })
+    }
+
+    pub fn go_through_crate_mover_9000_procedure(&mut self) -> ParseResult<()> {
+        while let Some(line) = self.lines.next() {
+            let Instruction {
+                num_moves,
+                stack_index_from,
+                stack_index_to,
+            } = self.parse_intruction(line)?;
+            for _ in 0..num_moves {
+                let crate_moved = self.stacks[stack_index_from].pop().unwrap();
+                self.stacks[stack_index_to].push(crate_moved);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn go_through_crate_mover_9001_procedure(&mut self) -> ParseResult<()> {
+        while let Some(line) = self.lines.next() {
+            let Instruction {
+                num_moves,
+                stack_index_from,
+                stack_index_to,
+            } = self.parse_intruction(line)?;
+
+            let stack_from_len = self.stacks[stack_index_from].len();
+            let crates_moved: Vec<_> = self.stacks[stack_index_from]
+                .drain(stack_from_len - num_moves..)
+                .collect();
+            self.stacks[stack_index_to].extend(crates_moved);
+        }
+
+        Ok(())
+    }
+
+    pub fn stacks(&self) -> &Stacks {
+        &self.stacks
+    }
+
+    pub fn top_crates(&self) -> String {
+        // Empty stacks have no top crate.
+        self.stacks
+            .iter()
+            .filter_map(|stack| stack.last())
+            .collect()
+    }
+}
+
+impl Solver for Day5Solver<'_> {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    // `size` is the number of instructions. They are simulated while being
+    // generated, so that they never move more crates than a stack holds.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        // Instructions only support single digit stack indices.
+        let num_of_stacks = rng.gen_range(1..=9);
+        let mut heights: Vec<usize> = (0..num_of_stacks).map(|_| rng.gen_range(0..=8)).collect();
+        heights[rng.gen_range(0..num_of_stacks)] += 1;
+
+        let mut lines = Vec::new();
+        for level in (0..*heights.iter().max().unwrap()).rev() {
+            let row: Vec<_> = heights
+                .iter()
+                .map(|&height| match level < height {
+                    true => format!("[{}]", char::from(b'A' + rng.gen_range(0..26))),
+                    false => "   ".to_owned(),
+                })
+                .collect();
+            lines.push(row.join(" "));
+        }
+        let indices: Vec<_> = (1..=num_of_stacks).map(|i| format!(" {i} ")).collect();
+        lines.push(indices.join(" "));
+        lines.push(String::new());
+
+        for _ in 0..size {
+            let non_empty_stacks: Vec<_> = (0..num_of_stacks).filter(|&i| heights[i] > 0).collect();
+            let stack_index_from = *non_empty_stacks.choose(rng).unwrap();
+            let stack_index_to = rng.gen_range(0..num_of_stacks);
+            let num_moves = rng.gen_range(1..=heights[stack_index_from]);
+            heights[stack_index_from] -= num_moves;
+            heights[stack_index_to] += num_moves;
+            lines.push(format!(
+                "move {num_moves} from {} to {}",
+                stack_index_from + 1,
+                stack_index_to + 1
+            ));
+        }
+        Some(lines.join("\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let mut solver = Day5Solver::new(input)?;
+        solver.parse_initial_configuration()?;
+        solver.go_through_crate_mover_9000_procedure()?;
+        Ok(solver.top_crates().into())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let mut solver = Day5Solver::new(input)?;
+        solver.parse_initial_configuration()?;
+        solver.go_through_crate_mover_9001_procedure()?;
+        Ok(solver.top_crates().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day5Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Text("CMZ".to_owned())
+        );
+        assert_eq!(
+            Day5Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Text("MCD".to_owned())
+        );
+    }
+
+    #[test]
+    fn parse_initial_configuration_with_empty_and_trimmed_stacks() {
+        // The 2nd stack is empty, and trailing blanks are trimmed.
+        let mut solver = Day5Solver::new("[A]         \n[B]     [C]\n 1   2   3\n\n").unwrap();
+        solver.parse_initial_configuration().unwrap();
+        assert_eq!(solver.stacks(), &vec![vec!['B', 'A'], vec![], vec!['C']]);
+        assert_eq!(solver.top_crates(), "AC");
+    }
+
+    #[test]
+    fn parse_initial_configuration_counts_stacks_from_their_indices() {
+        // Even the first line of the drawing is trimmed.
+        let mut solver =
+            Day5Solver::new("[A]\n[B] [C] [D]\n 1   2   3\n\nmove 1 from 1 to 3").unwrap();
+        solver.parse_initial_configuration().unwrap();
+        assert_eq!(solver.stacks(), &vec![vec!['B', 'A'], vec!['C'], vec!['D']]);
+        solver.go_through_crate_mover_9000_procedure().unwrap();
+        assert_eq!(solver.top_crates(), "BCA");
+
+        // A crate is drawn past the last stack.
+        let mut solver = Day5Solver::new("[A] [B] [C]\n 1   2\n").unwrap();
+        let error = solver.parse_initial_configuration().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 9));
+    }
+
+    #[test]
+    fn parse_initial_configuration_rejects_malformed_drawings() {
+        let mut solver = Day5Solver::new("[A] (B)\n 1   2\n").unwrap();
+        let error = solver.parse_initial_configuration().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 5));
+
+        // The indices of the stacks are missing.
+        let mut solver = Day5Solver::new("[A] [B]\n").unwrap();
+        let error = solver.parse_initial_configuration().unwrap_err();
+        assert_eq!(error.found, "end of input");
+    }
+}
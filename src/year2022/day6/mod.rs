@@ -0,0 +1,337 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::Result;
+use crate::parse;
+use crate::registry::{Entry, Solve};
+use crate::solver::{Part, Solver};
+
+pub const START_OF_PACKET_LENGTH: usize = 4;
+pub const START_OF_MESSAGE_LENGTH: usize = 14;
+
+pub struct Day6Solver {}
+
+impl Day6Solver {
+    // Rationale: read the datastream incrementally, so that huge datastreams
+    // never have to fit in memory. Slide a window over the datastream, one
+    // character at a time, while counting the occurrences of every character
+    // within the window, and how many of them are distinct. Both are updated
+    // in constant time, whatever the length of the marker.
+    pub fn solve_with_sliding_window(
+        reader: &mut dyn BufRead,
+        marker_length: usize,
+        expected: &str,
+    ) -> Result<Answer> {
+        let mut occurrences = [0usize; 256];
+        let mut num_distinct = 0;
+        let mut window = VecDeque::with_capacity(marker_length);
+        let mut marker = 0;
+        // Position right after the last character read, to report the end of the input.
+        let (mut line, mut column, mut after_newline) = (1, 0, false);
+
+        loop {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                return Err(parse::end_of_stream(line, column + 1, expected).into());
+            }
+
+            for &character in buffer {
+                marker += 1;
+                if after_newline {
+                    line += 1;
+                    column = 0;
+                }
+                after_newline = character == b'\n';
+                // Columns count characters, so only the 1st byte of UTF-8 characters.
+                if !after_newline && character & 0xC0 != 0x80 {
+                    column += 1;
+                }
+
+                if window.len() == marker_length {
+                    let oldest: u8 = window.pop_front().unwrap();
+                    occurrences[oldest as usize] -= 1;
+                    if occurrences[oldest as usize] == 0 {
+                        num_distinct -= 1;
+                    }
+                }
+                window.push_back(character);
+                occurrences[character as usize] += 1;
+                if occurrences[character as usize] == 1 {
+                    num_distinct += 1;
+                }
+
+                if num_distinct == marker_length {
+                    return Ok(marker.into());
+                }
+            }
+
+            let num_read = buffer.len();
+            reader.consume(num_read);
+        }
+    }
+
+    pub fn solve_part1_with_pairwise_comparisons(chars: &[u8]) -> Option<usize> {
+        for i in START_OF_PACKET_LENGTH..=chars.len() {
+            let start_of_packet = &chars[i - START_OF_PACKET_LENGTH..i];
+
+            if start_of_packet[0] != start_of_packet[1]
+                && start_of_packet[0] != start_of_packet[2]
+                && start_of_packet[0] != start_of_packet[3]
+                && start_of_packet[1] != start_of_packet[2]
+                && start_of_packet[1] != start_of_packet[3]
+                && start_of_packet[2] != start_of_packet[3]
+            {
+                return Some(i);
+            }
+        }
+
+        None
+    }
+
+    // Rationale: Move through candidate messages left to right.
+    // Collect the candidate message in a hashset.
+    // If the resulting hashset is the same size as the length
+    // of an expected message, then all characters are unique.
+    pub fn solve_part2_with_hashset(chars: &[u8]) -> Option<usize> {
+        // The marker may end with the last character.
+        for i in START_OF_MESSAGE_LENGTH..=chars.len() {
+            let start_of_message: HashSet<_> =
+                chars[i - START_OF_MESSAGE_LENGTH..i].iter().collect();
+            if start_of_message.len() == START_OF_MESSAGE_LENGTH {
+                return Some(i);
+            }
+        }
+
+        None
+    }
+
+    // Rationale: Move through candidate messages left to right.
+    // Check all pairs of characters within a message from left to right.
+    // If any are equal, move to the next candidate.
+    pub fn solve_part2_with_naive_loop(chars: &[u8]) -> Option<usize> {
+        'outer: for marker in START_OF_MESSAGE_LENGTH..=chars.len() {
+            let start_of_message = &chars[marker - START_OF_MESSAGE_LENGTH..marker];
+
+            for i in 0..(START_OF_MESSAGE_LENGTH - 1) {
+                for j in (i + 1)..START_OF_MESSAGE_LENGTH {
+                    if start_of_message[i] == start_of_message[j] {
+                        continue 'outer;
+                    }
+                }
+            }
+
+            return Some(marker);
+        }
+
+        None
+    }
+
+    // Rationale: Move through candidate messages left to right.
+    // Check all pairs of characters within a message from left to right.
+    // If any are equal, we apply a trick to move to the next candidate.
+    // Whereas the previous implementation just moved to the next one to the right,
+    // we can realize that if the Xth character in the message matched with the
+    // Yth character such that X < Y, then there's no point in moving to a next
+    // candidate message that still contains the two identical characters.
+    // Therefore, we can proceed with the candidate starting at character X+1,
+    // which potentially skips many characters everytime.
+    pub fn solve_part2_with_smart_loop(chars: &[u8]) -> Option<usize> {
+        let mut marker = START_OF_MESSAGE_LENGTH;
+        'outer: while marker <= chars.len() {
+            let start_of_message = &chars[marker - START_OF_MESSAGE_LENGTH..marker];
+
+            for i in 0..(START_OF_MESSAGE_LENGTH - 1) {
+                for j in (i + 1)..START_OF_MESSAGE_LENGTH {
+                    if start_of_message[i] == start_of_message[j] {
+                        marker += i + 1;
+                        continue 'outer;
+                    }
+                }
+            }
+
+            return Some(marker);
+        }
+
+        None
+    }
+
+    // Rationale: this is the same as the previous one, except that we compare
+    // characters within a message starting from the end of the message, and
+    // making our way back to the start of the message. That way, the trick
+    // presented in the previous solution will be maximized, as in we will skip
+    // the maximum number of characters for each candidate.
+    pub fn solve_part2_with_even_smarter_loop(chars: &[u8]) -> Option<usize> {
+        let mut marker = START_OF_MESSAGE_LENGTH;
+        'outer: while marker <= chars.len() {
+            let start_of_message = &chars[marker - START_OF_MESSAGE_LENGTH..marker];
+
+            for i in (0..(START_OF_MESSAGE_LENGTH - 1)).rev() {
+                for j in (i + 1)..START_OF_MESSAGE_LENGTH {
+                    if start_of_message[i] == start_of_message[j] {
+                        marker += i + 1;
+                        continue 'outer;
+                    }
+                }
+            }
+
+            return Some(marker);
+        }
+
+        None
+    }
+
+    fn answer_part1(input: &str, find_marker: fn(&[u8]) -> Option<usize>) -> Result<Answer> {
+        match find_marker(input.as_bytes()) {
+            Some(marker) => Ok(marker.into()),
+            None => Err(parse::end_of_input(input, "a start-of-packet marker").into()),
+        }
+    }
+
+    fn answer_part2(input: &str, find_marker: fn(&[u8]) -> Option<usize>) -> Result<Answer> {
+        match find_marker(input.as_bytes()) {
+            Some(marker) => Ok(marker.into()),
+            None => Err(parse::end_of_input(input, "a start-of-message marker").into()),
+        }
+    }
+}
+
+impl Solver for Day6Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    const STREAMING: bool = true;
+
+    fn entries() -> Vec<Entry> {
+        let entry = |part, implementation, solver| Entry {
+            year: Self::YEAR,
+            day: Self::DAY,
+            part,
+            implementation,
+            solver,
+        };
+
+        // The fastest implementations are the defaults, the sliding window only
+        // pays off on datastreams too large to be read whole.
+        vec![
+            entry(
+                Part::Part1,
+                "pairwise-comparisons",
+                Solve::Whole(|input| {
+                    Self::answer_part1(input, Self::solve_part1_with_pairwise_comparisons)
+                }),
+            ),
+            entry(
+                Part::Part1,
+                "sliding-window",
+                Solve::Streaming(Self::solve_part1_streaming),
+            ),
+            entry(
+                Part::Part2,
+                "even-smarter-loop",
+                Solve::Whole(|input| {
+                    Self::answer_part2(input, Self::solve_part2_with_even_smarter_loop)
+                }),
+            ),
+            entry(
+                Part::Part2,
+                "sliding-window",
+                Solve::Streaming(Self::solve_part2_streaming),
+            ),
+            entry(
+                Part::Part2,
+                "smart-loop",
+                Solve::Whole(|input| Self::answer_part2(input, Self::solve_part2_with_smart_loop)),
+            ),
+            entry(
+                Part::Part2,
+                "naive-loop",
+                Solve::Whole(|input| Self::answer_part2(input, Self::solve_part2_with_naive_loop)),
+            ),
+            entry(
+                Part::Part2,
+                "hashset",
+                Solve::Whole(|input| Self::answer_part2(input, Self::solve_part2_with_hashset)),
+            ),
+        ]
+    }
+
+    // `size` is the length of the datastream, which holds at least one
+    // start-of-message marker. A small alphabet makes other markers rare, so
+    // that the first one is found anywhere in the datastream, even at its end.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let alphabet_size = rng.gen_range(START_OF_MESSAGE_LENGTH..=26) as u8;
+        let mut datastream: Vec<_> = (0..size.saturating_sub(START_OF_MESSAGE_LENGTH))
+            .map(|_| b'a' + rng.gen_range(0..alphabet_size))
+            .collect();
+
+        let mut marker: Vec<_> = (b'a'..=b'z').collect();
+        marker.shuffle(rng);
+        let marker_position = rng.gen_range(0..=datastream.len());
+        datastream.splice(
+            marker_position..marker_position,
+            marker[..START_OF_MESSAGE_LENGTH].iter().copied(),
+        );
+        Some(String::from_utf8(datastream).unwrap())
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        Self::solve_with_sliding_window(reader, START_OF_PACKET_LENGTH, "a start-of-packet marker")
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        Self::solve_with_sliding_window(
+            reader,
+            START_OF_MESSAGE_LENGTH,
+            "a start-of-message marker",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    // Each datastream, along with the end of its start-of-packet and start-of-message markers.
+    const EXAMPLES: [(&str, i64, i64); 5] = [
+        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7, 19),
+        ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5, 23),
+        ("nppdvjthqldpwncqszvftbrmjlhg", 6, 23),
+        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10, 29),
+        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11, 26),
+    ];
+
+    #[test]
+    fn solves_examples_with_every_implementation() {
+        for (datastream, packet_marker, message_marker) in EXAMPLES {
+            for entry in registry::implementations(Day6Solver::YEAR, Day6Solver::DAY, Part::Part1) {
+                assert_eq!(
+                    entry.solve(datastream).unwrap(),
+                    Answer::Integer(packet_marker),
+                    "{} implementation",
+                    entry.implementation
+                );
+            }
+            for entry in registry::implementations(Day6Solver::YEAR, Day6Solver::DAY, Part::Part2) {
+                assert_eq!(
+                    entry.solve(datastream).unwrap(),
+                    Answer::Integer(message_marker),
+                    "{} implementation",
+                    entry.implementation
+                );
+            }
+        }
+    }
+}
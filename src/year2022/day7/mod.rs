@@ -0,0 +1,244 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use regex::Regex;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::iter::Peekable;
+use std::rc::Rc;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Lines};
+use crate::profile;
+use crate::solver::Solver;
+
+pub const FILE_SYSTEM_ALLOWED_SPACE: usize = 40_000_000;
+
+lazy_static! {
+    static ref FILE_SIZE_RE: Regex = Regex::new(r"^(\d+) .*$").unwrap();
+}
+
+#[derive(Default)]
+pub struct Folder {
+    pub total_size: usize,
+    pub sub_folders: HashMap<String, Rc<RefCell<Folder>>>,
+    pub parent_folder: Option<Rc<RefCell<Folder>>>,
+}
+
+impl Folder {
+    pub fn new(parent_folder: Option<Rc<RefCell<Folder>>>) -> Self {
+        Self {
+            total_size: 0,
+            sub_folders: HashMap::new(),
+            parent_folder,
+        }
+    }
+}
+
+pub struct Day7Solver {}
+
+impl Day7Solver {
+    pub fn go_through_files_listing(
+        lines: &mut Peekable<Lines>,
+        curr_folder: Rc<RefCell<Folder>>,
+    ) -> ParseResult<()> {
+        // If already visited the folder, stop.
+        if curr_folder.borrow().total_size > 0 {
+            return Ok(());
+        }
+
+        let mut sub_folders = HashMap::new();
+        let mut total_size = 0;
+        // Stop at the next command, or at the end of the file.
+        while let Some(file_listing) = lines.peek() {
+            if let Some(folder_name) = file_listing.strip_prefix("dir ") {
+                let sub_folder = Folder::new(Some(curr_folder.clone()));
+                sub_folders.insert(
+                    folder_name.text.to_owned(),
+                    Rc::new(RefCell::new(sub_folder)),
+                );
+            } else if let Some(capture) = FILE_SIZE_RE.captures(file_listing.text) {
+                total_size += file_listing
+                    .slice(capture.get(1).unwrap().range())
+                    .parse::<usize>("a file size")?;
+            } else {
+                break;
+            }
+
+            // We only peeked at the line, time to actually consume it.
+            lines.next();
+        }
+
+        let mut curr_folder = curr_folder.borrow_mut();
+        curr_folder.total_size = total_size;
+        curr_folder.sub_folders = sub_folders;
+        Ok(())
+    }
+
+    pub fn go_through_commands(
+        mut lines: Peekable<Lines>,
+        root_folder: Rc<RefCell<Folder>>,
+    ) -> ParseResult<()> {
+        let mut curr_folder = root_folder.clone();
+        while let Some(command) = lines.next() {
+            if command.text == "$ cd .." {
+                let parent_folder =
+                    curr_folder.borrow().parent_folder.clone().ok_or_else(|| {
+                        command.error("a folder to move into, as `/` has no parent")
+                    })?;
+                curr_folder = parent_folder;
+            } else if command.text == "$ cd /" {
+                curr_folder = root_folder.clone();
+            } else if let Some(folder_name) = command.strip_prefix("$ cd ") {
+                let sub_folder = curr_folder
+                    .borrow()
+                    .sub_folders
+                    .get(folder_name.text)
+                    .cloned()
+                    .ok_or_else(|| folder_name.error("a folder listed by `ls`"))?;
+                curr_folder = sub_folder;
+            } else if command.text == "$ ls" {
+                Self::go_through_files_listing(&mut lines, curr_folder.clone())?;
+            } else {
+                return Err(command.error("a `cd` or `ls` command"));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Lists the current folder, then moves into each of its sub-folders to list
+    // them in turn, within a budget of lines shared with the sub-folders.
+    fn generate_folder_session(rng: &mut StdRng, lines: &mut Vec<String>, budget: usize) {
+        let num_files = rng.gen_range(0..=4);
+        let mut num_folders = rng.gen_range(1..=4);
+        // Listing the folder, then moving in and out of each sub-folder.
+        let mut used = 1 + num_files + 3 * num_folders;
+        if used > budget {
+            num_folders = 0;
+            used = 1 + num_files;
+        }
+
+        lines.push("$ ls".to_owned());
+        let mut listing: Vec<_> = (0..num_folders)
+            .map(|i| format!("dir d{i}"))
+            .chain((0..num_files).map(|i| format!("{} f{i}.txt", rng.gen_range(1..=300_000))))
+            .collect();
+        listing.shuffle(rng);
+        lines.extend(listing);
+
+        let sub_folder_budget = budget.saturating_sub(used) / num_folders.max(1);
+        for i in 0..num_folders {
+            lines.push(format!("$ cd d{i}"));
+            Self::generate_folder_session(rng, lines, sub_folder_budget);
+            lines.push("$ cd ..".to_owned());
+        }
+    }
+
+    pub fn update_total_directory_sizes(folder: Rc<RefCell<Folder>>) -> usize {
+        let mut sub_total_size = 0;
+        let mut folder = folder.borrow_mut();
+        for sub_folder in folder.sub_folders.values() {
+            sub_total_size += Self::update_total_directory_sizes(sub_folder.clone());
+        }
+
+        folder.total_size += sub_total_size;
+        folder.total_size
+    }
+
+    pub fn compute_total_directory_sizes_at_most_100k(folder: Rc<RefCell<Folder>>) -> usize {
+        let mut total_size_at_most_100k = 0;
+        let folder = folder.borrow();
+        for sub_folder in folder.sub_folders.values() {
+            total_size_at_most_100k +=
+                Self::compute_total_directory_sizes_at_most_100k(sub_folder.clone());
+        }
+
+        if folder.total_size <= 100_000 {
+            total_size_at_most_100k += folder.total_size;
+        }
+        total_size_at_most_100k
+    }
+
+    pub fn find_smallest_directory_bigger_than(
+        threshold: usize,
+        folder: Rc<RefCell<Folder>>,
+    ) -> Option<usize> {
+        let folder = folder.borrow();
+        let smallest_directory_bigger_than = if folder.total_size >= threshold {
+            Some(folder.total_size)
+        } else {
+            None
+        };
+
+        folder
+            .sub_folders
+            .values()
+            .filter_map(|sub_folder| {
+                Self::find_smallest_directory_bigger_than(threshold, sub_folder.clone())
+            })
+            .chain(smallest_directory_bigger_than)
+            .min()
+    }
+}
+
+impl Solver for Day7Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    // `size` is roughly the number of lines of the terminal session.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let mut lines = vec!["$ cd /".to_owned()];
+        Self::generate_folder_session(rng, &mut lines, size);
+        Some(lines.join("\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let lines = parse::lines(input).peekable();
+
+        let root_folder = Folder::default();
+        let root_folder = Rc::new(RefCell::new(root_folder));
+        Self::go_through_commands(lines, root_folder.clone())?;
+        profile::mark_parsed();
+        Self::update_total_directory_sizes(root_folder.clone());
+
+        Ok(Self::compute_total_directory_sizes_at_most_100k(root_folder).into())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let lines = parse::lines(input).peekable();
+
+        let root_folder = Folder::default();
+        let root_folder = Rc::new(RefCell::new(root_folder));
+        Self::go_through_commands(lines, root_folder.clone())?;
+        profile::mark_parsed();
+        let root_folder_total_size = Self::update_total_directory_sizes(root_folder.clone());
+        let space_to_delete = root_folder_total_size.saturating_sub(FILE_SYSTEM_ALLOWED_SPACE);
+        // The root folder itself is always big enough.
+        Ok(
+            Self::find_smallest_directory_bigger_than(space_to_delete, root_folder)
+                .unwrap()
+                .into(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day7Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(95437)
+        );
+        assert_eq!(
+            Day7Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(24933642)
+        );
+    }
+}
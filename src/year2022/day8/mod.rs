@@ -1,14 +1,41 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse;
+use crate::profile;
 use crate::solver::Solver;
 
-const GRID_SIZE: usize = 99;
+/// Parses the heights of the trees, which must form a square grid.
+pub fn parse_heights(input: &str) -> ParseResult<Vec<Vec<i8>>> {
+    let grid_size = input.lines().count();
+    let mut heights = Vec::with_capacity(grid_size);
+    for line in parse::lines(input) {
+        let row = line
+            .chars()
+            .map(|(character, token)| match character.to_digit(10) {
+                Some(height) => Ok(height as i8),
+                None => Err(token.error("a tree height between 0 and 9")),
+            })
+            .collect::<ParseResult<Vec<_>>>()?;
+        if row.len() != grid_size {
+            return Err(line.error(format!("a row of {grid_size} trees")));
+        }
 
-struct TreePart1 {
-    height: i8,
-    visible: bool,
+        heights.push(row);
+    }
+
+    Ok(heights)
+}
+
+pub struct TreePart1 {
+    pub height: i8,
+    pub visible: bool,
 }
 
 impl TreePart1 {
-    fn new(height: i8) -> Self {
+    pub fn new(height: i8) -> Self {
         Self {
             height,
             visible: false,
@@ -17,32 +44,32 @@ impl TreePart1 {
 }
 
 #[derive(Default)]
-struct Visibility {
-    left: u8,
-    right: u8,
-    top: u8,
-    bottom: u8,
+pub struct Visibility {
+    pub left: usize,
+    pub right: usize,
+    pub top: usize,
+    pub bottom: usize,
 }
 
-struct TreePart2 {
-    height: i8,
-    visibility: Visibility,
+pub struct TreePart2 {
+    pub height: i8,
+    pub visibility: Visibility,
 }
 
-struct TreePart2Info {
-    height: i8,
-    visibility: u8,
+pub struct TreePart2Info {
+    pub height: i8,
+    pub visibility: usize,
 }
 
 impl TreePart2 {
-    fn new(height: i8) -> Self {
+    pub fn new(height: i8) -> Self {
         Self {
             height,
             visibility: Visibility::default(),
         }
     }
 
-    fn get_info(&self, traversal: Traversal, direction: Direction) -> TreePart2Info {
+    pub fn get_info(&self, traversal: Traversal, direction: Direction) -> TreePart2Info {
         let visibility = match (traversal, direction) {
             (Traversal::Horizontal, Direction::InOrder) => self.visibility.left,
             (Traversal::Horizontal, Direction::Reversed) => self.visibility.right,
@@ -56,7 +83,7 @@ impl TreePart2 {
         }
     }
 
-    fn set_visibility(&mut self, score: u8, traversal: Traversal, direction: Direction) {
+    pub fn set_visibility(&mut self, score: usize, traversal: Traversal, direction: Direction) {
         match (traversal, direction) {
             (Traversal::Horizontal, Direction::InOrder) => self.visibility.left = score,
             (Traversal::Horizontal, Direction::Reversed) => self.visibility.right = score,
@@ -65,51 +92,59 @@ impl TreePart2 {
         }
     }
 
-    fn scenic_score(&self) -> u32 {
-        self.visibility.left as u32
-            * self.visibility.right as u32
-            * self.visibility.top as u32
-            * self.visibility.bottom as u32
+    pub fn scenic_score(&self) -> usize {
+        self.visibility.left * self.visibility.right * self.visibility.top * self.visibility.bottom
     }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-enum Traversal {
+pub enum Traversal {
     Horizontal,
     Vertical,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-enum Direction {
+pub enum Direction {
     InOrder,
     Reversed,
 }
 
-type TreeMapPart1 = Vec<Vec<TreePart1>>;
-type TreeMapPart2 = Vec<Vec<TreePart2>>;
+pub type TreeMapPart1 = Vec<Vec<TreePart1>>;
+pub type TreeMapPart2 = Vec<Vec<TreePart2>>;
 
 pub struct Day8Solver {}
 impl Solver for Day8Solver {
-    fn solve_part1() {
-        let file = std::fs::read_to_string("src/day8/input.txt").unwrap();
-
-        let mut tree_map = TreeMapPart1::new();
-        for line in file.lines() {
-            tree_map.push(
-                line.chars()
-                    .map(|character| character.to_digit(10).unwrap() as i8)
-                    .map(TreePart1::new)
-                    .collect(),
-            );
-        }
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    // `size` is roughly the number of trees. The grid is square.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let grid_size = ((size as f64).sqrt() as usize).max(1);
+        let rows: Vec<String> = (0..grid_size)
+            .map(|_| {
+                (0..grid_size)
+                    .map(|_| char::from(b'0' + rng.gen_range(0..=9)))
+                    .collect()
+            })
+            .collect();
+        Some(rows.join("\n"))
+    }
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        let mut tree_map: TreeMapPart1 = parse_heights(input)?
+            .into_iter()
+            .map(|row| row.into_iter().map(TreePart1::new).collect())
+            .collect();
+        profile::mark_parsed();
+        let grid_size = tree_map.len();
 
         let mut num_trees_visible = 0;
         for traversal in [Traversal::Horizontal, Traversal::Vertical] {
             for direction in [Direction::InOrder, Direction::Reversed] {
-                let is: Vec<_> = (0..GRID_SIZE).collect();
+                let is: Vec<_> = (0..grid_size).collect();
                 let js: Vec<_> = match direction {
-                    Direction::InOrder => (0..GRID_SIZE).collect(),
-                    Direction::Reversed => (0..GRID_SIZE).rev().collect(),
+                    Direction::InOrder => (0..grid_size).collect(),
+                    Direction::Reversed => (0..grid_size).rev().collect(),
                 };
 
                 for i in &is {
@@ -134,28 +169,23 @@ impl Solver for Day8Solver {
             }
         }
 
-        println!("Number of visible trees: {num_trees_visible}");
+        Ok(num_trees_visible.into())
     }
 
-    fn solve_part2() {
-        let file = std::fs::read_to_string("src/day8/input.txt").unwrap();
-
-        let mut tree_map = TreeMapPart2::new();
-        for line in file.lines() {
-            tree_map.push(
-                line.chars()
-                    .map(|character| character.to_digit(10).unwrap() as i8)
-                    .map(TreePart2::new)
-                    .collect(),
-            );
-        }
+    fn solve_part2(input: &str) -> Result<Answer> {
+        let mut tree_map: TreeMapPart2 = parse_heights(input)?
+            .into_iter()
+            .map(|row| row.into_iter().map(TreePart2::new).collect())
+            .collect();
+        profile::mark_parsed();
+        let grid_size = tree_map.len();
 
         for traversal in [Traversal::Horizontal, Traversal::Vertical] {
             for direction in [Direction::InOrder, Direction::Reversed] {
-                let is: Vec<_> = (0..GRID_SIZE).collect();
+                let is: Vec<_> = (0..grid_size).collect();
                 let js: Vec<_> = match direction {
-                    Direction::InOrder => (0..GRID_SIZE).collect(),
-                    Direction::Reversed => (0..GRID_SIZE).rev().collect(),
+                    Direction::InOrder => (0..grid_size).collect(),
+                    Direction::Reversed => (0..grid_size).rev().collect(),
                 };
 
                 for i in is.iter() {
@@ -191,7 +221,7 @@ impl Solver for Day8Solver {
                             }
 
                             visibility_score += neighbour_info.visibility;
-                            j_index -= neighbour_info.visibility as usize;
+                            j_index -= neighbour_info.visibility;
                         }
 
                         let tree = match traversal {
@@ -205,12 +235,43 @@ impl Solver for Day8Solver {
             }
         }
 
-        let max_scenic_score = tree_map
+        // An empty grid has no tree, hence no scenic score.
+        Ok(tree_map
             .into_iter()
             .flatten()
             .map(|tree| tree.scenic_score())
             .max()
-            .unwrap();
-        println!("The maximum scenic score is: {max_scenic_score}.");
+            .unwrap_or_default()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("small.txt");
+
+    #[test]
+    fn solves_example() {
+        assert_eq!(
+            Day8Solver::solve_part1(EXAMPLE).unwrap(),
+            Answer::Integer(21)
+        );
+        assert_eq!(
+            Day8Solver::solve_part2(EXAMPLE).unwrap(),
+            Answer::Integer(8)
+        );
+    }
+
+    // Wider than 255 trees, so that viewing distances do not fit in a `u8`.
+    #[test]
+    fn solves_wide_grids() {
+        let mut rows = vec!["0".repeat(300); 300];
+        rows[150].replace_range(150..151, "9");
+        assert_eq!(
+            Day8Solver::solve_part2(&rows.join("\n")).unwrap(),
+            Answer::Integer(150 * 149 * 150 * 149)
+        );
     }
 }
@@ -0,0 +1,291 @@
+use rand::rngs::StdRng;
+use rand::Rng;
+use std::collections::HashSet;
+use std::io::BufRead;
+
+use crate::answer::Answer;
+use crate::error::{ParseResult, Result};
+use crate::parse::{self, Token};
+use crate::solver::Solver;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub fn parse(direction: Token) -> ParseResult<Self> {
+        match direction.text {
+            "U" => Ok(Self::Up),
+            "R" => Ok(Self::Right),
+            "D" => Ok(Self::Down),
+            "L" => Ok(Self::Left),
+            _ => Err(direction.error("one of `U`, `R`, `D` or `L`")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Motion {
+    pub direction: Direction,
+    pub length: u8,
+}
+
+impl Motion {
+    pub fn parse(motion: Token) -> ParseResult<Self> {
+        let (direction, length) = motion.split_once(' ', "a motion like `R 4`")?;
+        Ok(Self {
+            direction: Direction::parse(direction)?,
+            length: length.parse("a length between 0 and 255")?,
+        })
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Hash)]
+pub struct Position {
+    // x increases as we go right, and decreases as we go left.
+    pub x: i64,
+    // y increases as we go up, and decreases as we go down.
+    pub y: i64,
+}
+
+pub struct Rope {
+    knots: Vec<Position>,
+    tail_index: usize,
+    visited_tail_positions: HashSet<Position>,
+}
+
+impl Rope {
+    pub fn try_new(knots: usize) -> std::result::Result<Self, String> {
+        if knots < 2 {
+            Err(format!("`knots` must be at least 2, got {knots}"))
+        } else {
+            Ok(Self {
+                knots: vec![Position::default(); knots],
+                tail_index: knots - 1,
+                visited_tail_positions: HashSet::from([Position::default()]),
+            })
+        }
+    }
+
+    fn mut_head(&mut self) -> &mut Position {
+        &mut self.knots[0]
+    }
+
+    pub fn knots(&self) -> &[Position] {
+        &self.knots
+    }
+
+    pub fn tail(&self) -> Position {
+        self.knots[self.tail_index]
+    }
+
+    pub fn visited_tail_positions(&self) -> &HashSet<Position> {
+        &self.visited_tail_positions
+    }
+
+    pub fn apply_motion(&mut self, motion: Motion) {
+        'motion: for _ in 0..motion.length {
+            match motion.direction {
+                Direction::Up => {
+                    self.mut_head().y += 1;
+                }
+                Direction::Right => {
+                    self.mut_head().x += 1;
+                }
+                Direction::Down => {
+                    self.mut_head().y -= 1;
+                }
+                Direction::Left => {
+                    self.mut_head().x -= 1;
+                }
+            }
+
+            for knot in 0..self.tail_index {
+                let new_tail_position =
+                    Self::new_tail_position(self.knots[knot], self.knots[knot + 1]);
+
+                // New tail has not moved, it is pointless to pursue this motion further.
+                if new_tail_position == self.knots[knot + 1] {
+                    continue 'motion;
+                }
+
+                self.knots[knot + 1] = new_tail_position;
+            }
+
+            self.visited_tail_positions.insert(self.tail());
+        }
+    }
+
+    pub fn new_tail_position(relative_head: Position, mut relative_tail: Position) -> Position {
+        let tail_to_head_x_distance = relative_head.x - relative_tail.x;
+        let tail_to_head_y_distance = relative_head.y - relative_tail.y;
+
+        let mut tail_too_far_horizontally = false;
+        let mut tail_too_far_vertically = false;
+
+        // Update the x position of the tail if it's too far.
+        if tail_to_head_x_distance > 1 {
+            relative_tail.x += 1;
+            tail_too_far_horizontally = true;
+        } else if tail_to_head_x_distance < -1 {
+            relative_tail.x -= 1;
+            tail_too_far_horizontally = true;
+        }
+
+        // Update the y position of the tail if it's too far.
+        if tail_to_head_y_distance > 1 {
+            relative_tail.y += 1;
+            tail_too_far_vertically = true;
+        } else if tail_to_head_y_distance < -1 {
+            relative_tail.y -= 1;
+            tail_too_far_vertically = true;
+        }
+
+        if tail_too_far_horizontally && tail_too_far_vertically {
+            // Nothing to do, it's already fully moved.
+        } else if tail_too_far_horizontally {
+            relative_tail.y += tail_to_head_y_distance;
+        } else if tail_too_far_vertically {
+            relative_tail.x += tail_to_head_x_distance;
+        }
+
+        relative_tail
+    }
+}
+
+pub struct Day9Solver {}
+
+impl Solver for Day9Solver {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    // `size` is the number of motions.
+    fn generate_input(rng: &mut StdRng, size: usize) -> Option<String> {
+        let motions: Vec<_> = (0..size)
+            .map(|_| {
+                let direction = ['U', 'R', 'D', 'L'][rng.gen_range(0..4)];
+                format!("{direction} {}", rng.gen_range(1..=20))
+            })
+            .collect();
+        Some(motions.join("\n"))
+    }
+
+    const STREAMING: bool = true;
+
+    fn solve_part1(input: &str) -> Result<Answer> {
+        Self::solve_part1_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part2(input: &str) -> Result<Answer> {
+        Self::solve_part2_streaming(&mut input.as_bytes())
+    }
+
+    fn solve_part1_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut rope = Rope::try_new(2).unwrap();
+        for line in parse::read_lines(reader) {
+            rope.apply_motion(Motion::parse(line?.token())?);
+        }
+
+        Ok(rope.visited_tail_positions.len().into())
+    }
+
+    fn solve_part2_streaming(reader: &mut dyn BufRead) -> Result<Answer> {
+        let mut rope = Rope::try_new(10).unwrap();
+        for line in parse::read_lines(reader) {
+            rope.apply_motion(Motion::parse(line?.token())?);
+        }
+
+        Ok(rope.visited_tail_positions.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(x: i64, y: i64) -> Position {
+        Position { x, y }
+    }
+
+    #[test]
+    fn solves_examples() {
+        let example = include_str!("small.txt");
+        assert_eq!(
+            Day9Solver::solve_part1(example).unwrap(),
+            Answer::Integer(13)
+        );
+        assert_eq!(
+            Day9Solver::solve_part2(example).unwrap(),
+            Answer::Integer(1)
+        );
+        let larger_example = include_str!("small-2.txt");
+        assert_eq!(
+            Day9Solver::solve_part2(larger_example).unwrap(),
+            Answer::Integer(36)
+        );
+    }
+
+    #[test]
+    fn new_tail_position_when_touching_head() {
+        let tail = position(0, 0);
+        for head in [(0, 0), (1, 0), (0, -1), (1, 1), (-1, 1)] {
+            let head = position(head.0, head.1);
+            assert_eq!(
+                Rope::new_tail_position(head, tail),
+                tail,
+                "head at {head:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn new_tail_position_when_away_from_head() {
+        let tail = position(0, 0);
+        // In line with the head, the tail follows it.
+        assert_eq!(
+            Rope::new_tail_position(position(2, 0), tail),
+            position(1, 0)
+        );
+        assert_eq!(
+            Rope::new_tail_position(position(0, -2), tail),
+            position(0, -1)
+        );
+        // Otherwise, the tail moves diagonally.
+        assert_eq!(
+            Rope::new_tail_position(position(2, 1), tail),
+            position(1, 1)
+        );
+        assert_eq!(
+            Rope::new_tail_position(position(-1, -2), tail),
+            position(-1, -1)
+        );
+        // Only knots after the head can be diagonally away, when longer ropes move.
+        assert_eq!(
+            Rope::new_tail_position(position(2, -2), tail),
+            position(1, -1)
+        );
+    }
+
+    #[test]
+    fn rope_needs_a_head_and_a_tail() {
+        assert!(Rope::try_new(1).is_err());
+        assert_eq!(Rope::try_new(2).unwrap().knots().len(), 2);
+    }
+
+    #[test]
+    fn follows_the_head_far_from_the_start() {
+        let input = vec!["R 255"; 200].join("\n");
+        assert_eq!(
+            Day9Solver::solve_part1(&input).unwrap(),
+            Answer::Integer(200 * 255)
+        );
+        assert_eq!(
+            Day9Solver::solve_part2(&input).unwrap(),
+            Answer::Integer(200 * 255 - 8)
+        );
+    }
+}
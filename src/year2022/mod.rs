@@ -0,0 +1,35 @@
+//! Solvers of the [Advent Of Code 2022](https://adventofcode.com/2022) problems.
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+register_days! {
+    day1::Day1Solver,
+    day2::Day2Solver,
+    day3::Day3Solver,
+    day4::Day4Solver,
+    day5::Day5Solver,
+    day6::Day6Solver,
+    day7::Day7Solver,
+    day8::Day8Solver,
+    day9::Day9Solver,
+    day10::Day10Solver,
+    day11::Day11Solver,
+    day12::Day12Solver,
+    day13::Day13Solver,
+    day14::Day14Solver,
+    day15::Day15Solver,
+}